@@ -0,0 +1,122 @@
+use crate::prelude::*;
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Deserialize, TypeUuid)]
+#[uuid = "c3f1d8a2-6b5e-4f0a-9d27-81e4b6a05c19"]
+pub struct ShopCatalog {
+    pub offers: usize,
+    pub entries: Vec<ShopEntry>,
+}
+
+#[derive(Deserialize)]
+pub struct ShopEntry {
+    pub item: Item,
+    pub price: ShopPrice,
+    #[serde(default)]
+    pub stock: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct ShopPrice {
+    pub normal: u32,
+    pub hard: u32,
+}
+
+impl ShopPrice {
+    pub fn get(&self, difficulty: &Difficulty) -> u32 {
+        match *difficulty {
+            Difficulty::Normal => self.normal,
+            Difficulty::Hard => self.hard,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ShopCatalogError {
+    NoEntries,
+    NoOffers,
+    NotAnItem { entry: usize },
+    Duplicate { entry: usize, item: Item },
+}
+
+impl fmt::Display for ShopCatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShopCatalogError::NoEntries => write!(f, "shop has no entries"),
+            ShopCatalogError::NoOffers => write!(f, "shop offers no items per visit"),
+            ShopCatalogError::NotAnItem { entry } => {
+                write!(f, "entry {} does not sell an item", entry)
+            }
+            ShopCatalogError::Duplicate { entry, item } => {
+                write!(
+                    f,
+                    "entry {} sells {:?}, which is already listed",
+                    entry, item
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShopCatalogError {}
+
+impl ShopCatalog {
+    pub fn validate(&self) -> Result<(), ShopCatalogError> {
+        if self.entries.is_empty() {
+            return Err(ShopCatalogError::NoEntries);
+        }
+        if self.offers == 0 {
+            return Err(ShopCatalogError::NoOffers);
+        }
+        for (entry, shop_entry) in self.entries.iter().enumerate() {
+            if shop_entry.item == Item::None {
+                return Err(ShopCatalogError::NotAnItem { entry });
+            }
+            if self.entries[..entry]
+                .iter()
+                .any(|other| other.item == shop_entry.item)
+            {
+                return Err(ShopCatalogError::Duplicate {
+                    entry,
+                    item: shop_entry.item,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn in_stock(&self, sold: &HashMap<Item, u32>) -> Vec<&ShopEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| match entry.stock {
+                Some(stock) => sold.get(&entry.item).copied().unwrap_or(0) < stock,
+                None => true,
+            })
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct ShopCatalogLoader;
+
+impl AssetLoader for ShopCatalogLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let catalog = ron::de::from_bytes::<ShopCatalog>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(catalog));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["shop.ron"]
+    }
+}
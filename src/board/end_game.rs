@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::save::delete_save;
 use bevy::prelude::*;
 use bevy_kira_audio::Audio;
 
@@ -7,6 +8,7 @@ pub enum EndGameState {
     Win2,
     Lose,
     Tie,
+    Ranking,
 }
 
 pub struct EndGame {
@@ -27,23 +29,86 @@ impl Plugin for EndGamePlugin {
 
 pub fn init(
     mut end_game: ResMut<EndGame>,
-    game: Res<Game>,
-    board: Res<Board>,
+    mut game: ResMut<Game>,
+    mut players: ResMut<Players>,
     mut dialogue: ResMut<Dialogue>,
     mut camera_controller: ResMut<CameraController>,
+    ruleset: Res<Ruleset>,
+    mode: Res<GameMode>,
 ) {
-    if game.my_coins == 0 && game.your_coins == 0 {
+    delete_save();
+    if mode.stars() {
+        end_game.state = EndGameState::Ranking;
+        let ranking = players.ranking();
+        camera_controller.follow_entity = ranking
+            .first()
+            .and_then(|winner| players.list[*winner].pawn);
+        dialogue.add(DialogueEntry {
+            text: "That was the last turn! Let's see who collected the most stars.".into(),
+            ..Default::default()
+        });
+        for (place, player) in ranking.iter().enumerate() {
+            dialogue.add(DialogueEntry {
+                text: format!(
+                    "{}: {} with {} stars and {} coins.",
+                    place_name(place),
+                    players.subject(*player),
+                    players.list[*player].stars,
+                    players.coins(*player)
+                ),
+                ..Default::default()
+            });
+        }
+        return;
+    }
+    let host_broke = players.coins(Players::HOST) == 0;
+    let broke = players.broke_challenger();
+    if host_broke && broke.is_some() {
         end_game.state = EndGameState::Tie;
-    } else if game.your_coins == 0 {
+    } else if broke.is_some() {
         end_game.state = EndGameState::Lose;
-    } else if game.my_coins == 0 {
+    } else if host_broke {
         end_game.state = EndGameState::Win;
+        game.challenger = players.richest_challenger();
+        let host = &mut players.list[Players::HOST];
+        host.item_use_slot = host
+            .items
+            .iter()
+            .position(|item| *item == Item::TrumpCard)
+            .unwrap_or(0);
     }
+    let broke = broke.unwrap_or(1);
+    let who = if players.len() == 2 {
+        "you".to_string()
+    } else {
+        players.subject(broke)
+    };
+    let loss = if players.len() == 2 {
+        "You lose! Sorry!".to_string()
+    } else {
+        format!("{} loses! Sorry!", who)
+    };
+    let broke_pawn = players.list.get(broke).and_then(|player| player.pawn);
     match end_game.state {
+        EndGameState::Tie if !ruleset.rigged() => {
+            camera_controller.follow_entity = broke_pawn;
+            dialogue.add(DialogueEntry {
+                text: format!("Oh.. it looks like {} ran out of coins.", who),
+                ..Default::default()
+            });
+            dialogue.add(DialogueEntry {
+                text: "So did I! It's a tie!".into(),
+                ..Default::default()
+            });
+            dialogue.add(DialogueEntry {
+                text: "Good game.".into(),
+                ..Default::default()
+            });
+        }
         EndGameState::Tie => {
-            camera_controller.follow_entity = board.your_pawn;
+            camera_controller.follow_entity = broke_pawn;
             dialogue.add(DialogueEntry {
-                text: "Oh.. it looks like you ran out of coins.".into(),
+                text: format!("Oh.. it looks like {} ran out of coins.", who),
                 ..Default::default()
             });
             dialogue.add(DialogueEntry {
@@ -51,7 +116,7 @@ pub fn init(
                 ..Default::default()
             });
             dialogue.add(DialogueEntry {
-                text: "You lose! Sorry!".into(),
+                text: loss,
                 ..Default::default()
             });
             dialogue.add(DialogueEntry {
@@ -60,7 +125,7 @@ pub fn init(
             });
         }
         EndGameState::Win => {
-            camera_controller.follow_entity = board.my_pawn;
+            camera_controller.follow_entity = players.list[Players::HOST].pawn;
             dialogue.add(DialogueEntry {
                 text: "...".into(),
                 ..Default::default()
@@ -74,13 +139,19 @@ pub fn init(
                 ..Default::default()
             });
         }
-        EndGameState::Win2 => {}
+        EndGameState::Win2 | EndGameState::Ranking => {}
         EndGameState::Lose => {
-            camera_controller.follow_entity = board.your_pawn;
+            camera_controller.follow_entity = broke_pawn;
             dialogue.add(DialogueEntry {
-                text: "Oh.. it looks like you ran out of coins.".into(),
+                text: format!("Oh.. it looks like {} ran out of coins.", who),
                 ..Default::default()
             });
+            if players.len() > 2 {
+                dialogue.add(DialogueEntry {
+                    text: loss,
+                    ..Default::default()
+                });
+            }
             dialogue.add(DialogueEntry {
                 text: "Better luck next time.".into(),
                 ..Default::default()
@@ -89,44 +160,80 @@ pub fn init(
     }
 }
 
+fn place_name(place: usize) -> String {
+    match place {
+        0 => "1st".into(),
+        1 => "2nd".into(),
+        2 => "3rd".into(),
+        _ => format!("{}th", place + 1),
+    }
+}
+
 pub fn update(
     mut end_game: ResMut<EndGame>,
     mut dialogue: ResMut<Dialogue>,
-    mut reset: EventWriter<GameResetSend>,
-    mut board: ResMut<Board>,
+    mut game: ResMut<Game>,
+    mut players: ResMut<Players>,
     mut game_state: ResMut<State<GameState>>,
-    time: Res<Time>,
+    time: Res<Clock>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    item_effects: Res<ItemEffects>,
+    difficulty: Res<Difficulty>,
+    ruleset: Res<Ruleset>,
+    mut game_rng: ResMut<GameRng>,
+    mut history: ResMut<History>,
+    mini_games: Res<MiniGames>,
 ) {
     if !dialogue.busy() {
         match end_game.state {
             EndGameState::Tie => {
-                reset.send(GameResetSend);
+                game_state.set(GameState::Stats).unwrap();
             }
             EndGameState::Win => {
-                if board.my_item_use_interpolate == 0. {
+                let host = &mut players.list[Players::HOST];
+                if host.item_use_interpolate == 0. {
                     audio.play(asset_library.audio("itemuse"));
                 }
-                board.my_item_use_interpolate += time.delta_seconds() * 0.75;
-                board.my_item_use_interpolate = board.my_item_use_interpolate.clamp(0., 1.);
-                if board.my_item_use_interpolate >= 1. {
-                    dialogue.add(DialogueEntry {
-                        text: "I use my trump card!".into(),
-                        ..Default::default()
-                    });
-                    dialogue.add(DialogueEntry {
-                        text: "Prepare yourself!".into(),
-                        ..Default::default()
-                    });
+                host.item_use_interpolate += time.delta_seconds() * 0.75;
+                host.item_use_interpolate = host.item_use_interpolate.clamp(0., 1.);
+                if host.item_use_interpolate >= 1. {
+                    let item = host.using_item();
+                    if let Some(effect) = item_effects.get(item) {
+                        history.record(
+                            game.turn,
+                            HistoryEvent::UsedItem {
+                                player: Players::HOST,
+                                item,
+                            },
+                        );
+                        let mut context = ItemContext::new(
+                            Players::HOST,
+                            &game,
+                            &players,
+                            &difficulty,
+                            &ruleset,
+                            game_rng.stream(RngStream::Dice),
+                        );
+                        let actions = effect.use_item(&mut context);
+                        apply_item_actions(
+                            actions,
+                            &mut context,
+                            &mut game,
+                            &mut players,
+                            &mut dialogue,
+                            &mini_games,
+                            &history,
+                        );
+                    }
                     end_game.state = EndGameState::Win2;
                 }
             }
             EndGameState::Win2 => {
                 game_state.set(GameState::EndGame).unwrap();
             }
-            EndGameState::Lose => {
-                reset.send(GameResetSend);
+            EndGameState::Lose | EndGameState::Ranking => {
+                game_state.set(GameState::Stats).unwrap();
             }
         }
     }
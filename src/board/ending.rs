@@ -18,13 +18,17 @@ impl Plugin for EndingPlugin {
 }
 
 pub fn init(
-    game: Res<Game>,
+    mut game: ResMut<Game>,
+    players: Res<Players>,
     mut ending: ResMut<Ending>,
     mut camera_controller: ResMut<CameraController>,
     mut dialogue: ResMut<Dialogue>,
 ) {
     ending.animation = Some(Animation::stub());
     camera_controller.follow_entity = None;
+    if !game.duel {
+        game.challenger = players.challenger_for_turn(game.turn);
+    }
     if game.turn == 1 {
         dialogue.add(DialogueEntry {
             text: "Now we play a mini game!".into(),
@@ -46,17 +50,18 @@ pub fn cleanup(mut ending: ResMut<Ending>) {
 }
 
 pub fn update(
-    game: Res<Game>,
     mut ending: ResMut<Ending>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut game_state: ResMut<State<GameState>>,
     mut board_state: ResMut<State<BoardState>>,
     dialogue: Res<Dialogue>,
+    players: Res<Players>,
+    mode: Res<GameMode>,
 ) {
     if dialogue.busy() {
         return;
     }
-    if game.my_coins == 0 || game.your_coins == 0 {
+    if players.broke(&mode) {
         board_state.set(BoardState::EndGame).unwrap();
         return;
     }
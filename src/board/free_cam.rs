@@ -1,17 +1,38 @@
+use super::pawn::Pawn;
 use crate::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::window::CursorMoved;
+
+const MIN_ZOOM: f32 = 0.35;
+const MAX_ZOOM: f32 = 1.5;
+const ZOOM_STEP: f32 = 0.9;
+const PAN_SPEED: f32 = 10.;
 
 #[derive(Component)]
 pub struct LocalEntity;
 
+#[derive(Component)]
+pub struct FreeCamLabel;
+
+#[derive(Default)]
+pub struct FreeCam {
+    pub labels: bool,
+    pawn_index: usize,
+    drag_position: Option<Vec2>,
+    cursor_position: Option<Vec2>,
+}
+
 pub struct FreeCamPlugin;
 
 impl Plugin for FreeCamPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(BoardState::FreeCam).with_system(init))
+        app.init_resource::<FreeCam>()
+            .add_system_set(SystemSet::on_enter(BoardState::FreeCam).with_system(init))
             .add_system_set(SystemSet::on_exit(BoardState::FreeCam).with_system(cleanup))
             .add_system_set(SystemSet::on_update(BoardState::FreeCam).with_system(update))
-            .add_system_set(SystemSet::on_update(BoardState::FreeCam).with_system(move_camera));
+            .add_system_set(SystemSet::on_update(BoardState::FreeCam).with_system(move_camera))
+            .add_system_set(SystemSet::on_update(BoardState::FreeCam).with_system(zoom_camera));
     }
 }
 
@@ -19,11 +40,21 @@ pub fn init(
     mut board: ResMut<Board>,
     mut camera_controller: ResMut<CameraController>,
     mut commands: Commands,
+    mut free_cam: ResMut<FreeCam>,
     asset_library: Res<AssetLibrary>,
+    players: Res<Players>,
+    tile_query: Query<(&Tile, &Transform)>,
+    pawn_query: Query<(&Pawn, &Transform)>,
 ) {
     board.score_overlay = false;
+    free_cam.pawn_index = board
+        .active_pawn
+        .and_then(|active_pawn| pawn_query.get(active_pawn).ok())
+        .map(|(pawn, _)| pawn.player)
+        .unwrap_or(0);
+    free_cam.drag_position = None;
     camera_controller.follow_entity = None;
-    camera_controller.zoom_out = true;
+    camera_controller.zoom = CameraController::ZOOM_OUT;
     commands
         .spawn_bundle(NodeBundle {
             style: Style {
@@ -46,7 +77,7 @@ pub fn init(
                     ..Default::default()
                 },
                 text: Text::with_section(
-                    "WASD - Look Around\nF - Exit Free Cam",
+                    "WASD / Drag - Look Around\nWheel - Zoom   P - Jump to Pawn\nL - Labels   F - Exit Free Cam",
                     TextStyle {
                         font: asset_library.font("game"),
                         font_size: 24.0,
@@ -61,6 +92,63 @@ pub fn init(
             });
         })
         .insert(LocalEntity);
+    for (tile, transform) in tile_query.iter() {
+        spawn_label(
+            &mut commands,
+            &asset_library,
+            tile.tile_type.name(),
+            Color::BLACK,
+            transform.translation.truncate() - Vec2::new(0., 22.),
+            free_cam.labels,
+        );
+    }
+    for (pawn, transform) in pawn_query.iter() {
+        let name = if pawn.player == Players::HOST {
+            "Host".to_string()
+        } else {
+            format!("Player {}", pawn.player)
+        };
+        spawn_label(
+            &mut commands,
+            &asset_library,
+            &name,
+            players.color(pawn.player),
+            transform.translation.truncate() + Vec2::new(0., 30.),
+            free_cam.labels,
+        );
+    }
+}
+
+fn spawn_label(
+    commands: &mut Commands,
+    asset_library: &AssetLibrary,
+    text: &str,
+    color: Color,
+    position: Vec2,
+    visible: bool,
+) {
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text::with_section(
+                text,
+                TextStyle {
+                    color,
+                    font: asset_library.font("game"),
+                    font_size: 14.,
+                },
+                TextAlignment {
+                    vertical: VerticalAlign::Center,
+                    horizontal: HorizontalAlign::Center,
+                },
+            ),
+            transform: Transform::from_xyz(position.x, position.y, 5.),
+            visibility: Visibility {
+                is_visible: visible,
+            },
+            ..Default::default()
+        })
+        .insert(FreeCamLabel)
+        .insert(LocalEntity);
 }
 
 pub fn cleanup(
@@ -72,23 +160,44 @@ pub fn cleanup(
     if let Some(active_pawn) = board.active_pawn {
         camera_controller.follow_entity = Some(active_pawn);
     }
-    camera_controller.zoom_out = false;
+    camera_controller.zoom = CameraController::ZOOM_IN;
     board.score_overlay = true;
     for entity in local_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
 }
 
-pub fn update(mut input: ResMut<Input<KeyCode>>, mut board_state: ResMut<State<BoardState>>) {
+pub fn update(
+    mut input: ResMut<Input<KeyCode>>,
+    mut board_state: ResMut<State<BoardState>>,
+    mut free_cam: ResMut<FreeCam>,
+    mut camera_controller: ResMut<CameraController>,
+    players: Res<Players>,
+    mut label_query: Query<&mut Visibility, With<FreeCamLabel>>,
+) {
     if input.just_pressed(KeyCode::F) {
         board_state.set(BoardState::TurnInput).unwrap();
         input.reset(KeyCode::F);
+        return;
+    }
+    if input.just_pressed(KeyCode::L) {
+        free_cam.labels = !free_cam.labels;
+        for mut visibility in label_query.iter_mut() {
+            visibility.is_visible = free_cam.labels;
+        }
+    }
+    if input.just_pressed(KeyCode::P) && !players.is_empty() {
+        free_cam.pawn_index = (free_cam.pawn_index + 1) % players.len();
+        camera_controller.follow_entity = players.list[free_cam.pawn_index].pawn;
     }
 }
 
 pub fn move_camera(
-    mut camera_query: Query<&mut Transform, With<GameCamera>>,
+    mut camera_controller: ResMut<CameraController>,
+    mut free_cam: ResMut<FreeCam>,
     input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    mut cursor_moved: EventReader<CursorMoved>,
 ) {
     let mut movement = Vec2::ZERO;
     if input.pressed(KeyCode::S) {
@@ -103,11 +212,47 @@ pub fn move_camera(
     if input.pressed(KeyCode::D) {
         movement.x += 1.;
     }
-    movement = movement.normalize_or_zero() * 10.;
-    for mut transform in camera_query.iter_mut() {
-        transform.translation.x += movement.x;
-        transform.translation.y += movement.y;
-        transform.translation.x = transform.translation.x.clamp(-240., 240.);
-        transform.translation.y = transform.translation.y.clamp(-240., 270.);
+    let mut pan = movement.normalize_or_zero() * PAN_SPEED;
+    if let Some(event) = cursor_moved.iter().last() {
+        free_cam.cursor_position = Some(event.position);
+    }
+    let cursor_position = free_cam.cursor_position;
+    if mouse_input.pressed(MouseButton::Left) {
+        if let (Some(drag_position), Some(cursor_position)) =
+            (free_cam.drag_position, cursor_position)
+        {
+            pan -= (cursor_position - drag_position) * camera_controller.scale();
+        }
+        free_cam.drag_position = cursor_position;
+    } else {
+        free_cam.drag_position = None;
+    }
+    if pan != Vec2::ZERO {
+        camera_controller.follow_entity = None;
+        camera_controller.focus += pan;
+    }
+}
+
+pub fn zoom_camera(
+    mut camera_controller: ResMut<CameraController>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    windows: Res<Windows>,
+) {
+    let mut steps = 0.;
+    for event in mouse_wheel.iter() {
+        steps += match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / 40.,
+        };
+    }
+    if steps == 0. {
+        return;
+    }
+    let mut max_zoom = MAX_ZOOM;
+    if let (Some(bounds), Some(window)) = (camera_controller.bounds, windows.get_primary()) {
+        let fit = (bounds.max - bounds.min) / Vec2::new(window.width(), window.height());
+        max_zoom = max_zoom.min(fit.max_element()).max(MIN_ZOOM);
     }
+    camera_controller.zoom =
+        (camera_controller.zoom * ZOOM_STEP.powf(steps)).clamp(MIN_ZOOM, max_zoom);
 }
@@ -0,0 +1,173 @@
+use crate::history::HISTORY_TURNS;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+const PANEL_LINES: usize = 12;
+const PANEL_COLOR: Color = Color::rgba(0., 0., 0., 0.8);
+
+#[derive(Component)]
+pub struct HistoryPanelNode;
+
+#[derive(Component)]
+pub struct HistoryPanelText;
+
+#[derive(Default)]
+pub struct HistoryPanel {
+    pub open: bool,
+    scroll: usize,
+}
+
+pub struct HistoryPanelPlugin;
+
+impl Plugin for HistoryPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HistoryPanel>()
+            .add_system(init)
+            .add_system(input)
+            .add_system(update);
+    }
+}
+
+pub fn init(
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    mut history_panel: ResMut<HistoryPanel>,
+    mut asset_library_ready: EventReader<AssetLibraryReady>,
+) {
+    for _ in asset_library_ready.iter() {
+        *history_panel = HistoryPanel::default();
+        commands
+            .spawn_bundle(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: Rect {
+                        left: Val::Px(110.),
+                        top: Val::Px(100.),
+                        ..Default::default()
+                    },
+                    size: Size::new(Val::Px(420.), Val::Px(280.)),
+                    padding: Rect::all(Val::Px(10.)),
+                    ..Default::default()
+                },
+                visibility: Visibility { is_visible: false },
+                color: PANEL_COLOR.into(),
+                ..Default::default()
+            })
+            .insert(HistoryPanelNode)
+            .with_children(|parent| {
+                parent
+                    .spawn_bundle(TextBundle {
+                        style: Style {
+                            align_self: AlignSelf::FlexEnd,
+                            ..Default::default()
+                        },
+                        visibility: Visibility { is_visible: false },
+                        text: Text::with_section(
+                            "",
+                            TextStyle {
+                                font: asset_library.font("game"),
+                                font_size: 16.0,
+                                color: Color::WHITE,
+                            },
+                            TextAlignment {
+                                horizontal: HorizontalAlign::Left,
+                                vertical: VerticalAlign::Top,
+                            },
+                        ),
+                        ..Default::default()
+                    })
+                    .insert(HistoryPanelText);
+            });
+    }
+}
+
+pub fn input(
+    keys: Res<Input<KeyCode>>,
+    game_state: Res<State<GameState>>,
+    mut history_panel: ResMut<HistoryPanel>,
+) {
+    if *game_state.current() != GameState::Board {
+        history_panel.open = false;
+        return;
+    }
+    if keys.just_pressed(KeyCode::H) {
+        history_panel.open = !history_panel.open;
+        history_panel.scroll = 0;
+    }
+    if history_panel.open {
+        if keys.just_pressed(KeyCode::PageDown) {
+            history_panel.scroll += 1;
+        }
+        if keys.just_pressed(KeyCode::PageUp) {
+            history_panel.scroll = history_panel.scroll.saturating_sub(1);
+        }
+    }
+}
+
+pub fn history_lines(
+    history: &History,
+    turn: u32,
+    players: &Players,
+    item_effects: &ItemEffects,
+    mini_games: &MiniGames,
+) -> Vec<String> {
+    let mut lines = vec![];
+    for shown_turn in (turn.saturating_sub(HISTORY_TURNS - 1)..=turn).rev() {
+        let events: Vec<String> = history
+            .recent(turn)
+            .filter(|entry| entry.turn == shown_turn)
+            .map(|entry| {
+                format!(
+                    "  {}",
+                    entry.event.describe(players, item_effects, mini_games)
+                )
+            })
+            .collect();
+        if events.is_empty() {
+            continue;
+        }
+        lines.push(format!("Turn {}", shown_turn));
+        lines.extend(events);
+    }
+    lines
+}
+
+pub fn update(
+    game: Res<Game>,
+    players: Res<Players>,
+    history: Res<History>,
+    item_effects: Res<ItemEffects>,
+    mini_games: Res<MiniGames>,
+    mut history_panel: ResMut<HistoryPanel>,
+    mut node_query: Query<&mut Visibility, With<HistoryPanelNode>>,
+    mut text_query: Query<
+        (&mut Visibility, &mut Text),
+        (With<HistoryPanelText>, Without<HistoryPanelNode>),
+    >,
+) {
+    for mut visibility in node_query.iter_mut() {
+        visibility.is_visible = history_panel.open;
+    }
+    for (mut visibility, mut text) in text_query.iter_mut() {
+        visibility.is_visible = history_panel.open;
+        if !history_panel.open {
+            continue;
+        }
+        let lines = history_lines(&history, game.turn, &players, &item_effects, &mini_games);
+        let max_scroll = lines.len().saturating_sub(PANEL_LINES);
+        history_panel.scroll = history_panel.scroll.min(max_scroll);
+        let mut value = String::from("History  (H - Close, PgUp/PgDn - Scroll)\n");
+        if lines.is_empty() {
+            value.push_str("Nothing has happened yet.");
+        } else {
+            let shown: Vec<&str> = lines
+                .iter()
+                .skip(history_panel.scroll)
+                .take(PANEL_LINES)
+                .map(|line| line.as_str())
+                .collect();
+            value.push_str(&shown.join("\n"));
+        }
+        text.sections[0].value = value;
+    }
+}
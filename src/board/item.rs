@@ -1,8 +1,301 @@
-#[derive(Debug, Clone, Copy)]
+use super::moving::roll;
+use crate::prelude::*;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Item {
     None,
     Rapier,
     CrystalBall,
     TrumpCard,
-    TestItem,
+    DoubleDice,
+    PickRoll,
+}
+
+pub struct ItemContext<'a> {
+    pub player: usize,
+    pub opponent: usize,
+    pub name: String,
+    pub turn: u32,
+    pub difficulty: &'a Difficulty,
+    pub ruleset: &'a Ruleset,
+    pub rng: &'a mut StdRng,
+}
+
+impl<'a> ItemContext<'a> {
+    pub fn new(
+        player: usize,
+        game: &Game,
+        players: &Players,
+        difficulty: &'a Difficulty,
+        ruleset: &'a Ruleset,
+        rng: &'a mut StdRng,
+    ) -> Self {
+        let opponent = if player == Players::HOST {
+            game.challenger
+        } else {
+            Players::HOST
+        };
+        Self {
+            player,
+            opponent,
+            name: players.subject(player),
+            turn: game.turn,
+            difficulty,
+            ruleset,
+            rng,
+        }
+    }
+}
+
+pub enum ItemAction {
+    Duel,
+    RevealMiniGame,
+    RevealRoll,
+    Dice(Vec<Die>),
+    PickRoll,
+    Say(String),
+}
+
+pub trait ItemEffect: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn icon(&self) -> &'static str {
+        "item_mystery"
+    }
+
+    fn description(&self) -> &'static str;
+
+    fn prompt(&self) -> String {
+        format!("Use {}", self.name())
+    }
+
+    fn use_item(&self, context: &mut ItemContext) -> Vec<ItemAction>;
+}
+
+pub struct ItemEffects {
+    effects: Vec<(Item, Box<dyn ItemEffect>)>,
+}
+
+impl ItemEffects {
+    pub fn register(&mut self, item: Item, effect: impl ItemEffect + 'static) {
+        self.effects.retain(|(registered, _)| *registered != item);
+        self.effects.push((item, Box::new(effect)));
+    }
+
+    pub fn get(&self, item: Item) -> Option<&dyn ItemEffect> {
+        self.effects
+            .iter()
+            .find(|(registered, _)| *registered == item)
+            .map(|(_, effect)| effect.as_ref())
+    }
+}
+
+impl Default for ItemEffects {
+    fn default() -> Self {
+        let mut item_effects = Self { effects: vec![] };
+        item_effects.register(Item::Rapier, RapierEffect);
+        item_effects.register(Item::CrystalBall, CrystalBallEffect);
+        item_effects.register(Item::TrumpCard, TrumpCardEffect);
+        item_effects.register(Item::DoubleDice, DoubleDiceEffect);
+        item_effects.register(Item::PickRoll, PickRollEffect);
+        item_effects
+    }
+}
+
+pub struct RapierEffect;
+
+impl ItemEffect for RapierEffect {
+    fn name(&self) -> &'static str {
+        "Rapier"
+    }
+
+    fn icon(&self) -> &'static str {
+        "item_rapier"
+    }
+
+    fn description(&self) -> &'static str {
+        "Turn the next\nmini game into\na duel."
+    }
+
+    fn use_item(&self, context: &mut ItemContext) -> Vec<ItemAction> {
+        vec![
+            ItemAction::Say(format!("{} used the rapier!", context.name)),
+            ItemAction::Say("The mini game will now be a duel.".into()),
+            ItemAction::Duel,
+        ]
+    }
+}
+
+pub struct CrystalBallEffect;
+
+impl ItemEffect for CrystalBallEffect {
+    fn name(&self) -> &'static str {
+        "Crystal Ball"
+    }
+
+    fn description(&self) -> &'static str {
+        "See the next\nmini game and\nyour rival's roll."
+    }
+
+    fn use_item(&self, context: &mut ItemContext) -> Vec<ItemAction> {
+        vec![
+            ItemAction::Say(format!("{} used the crystal ball!", context.name)),
+            ItemAction::RevealMiniGame,
+            ItemAction::RevealRoll,
+        ]
+    }
+}
+
+pub struct TrumpCardEffect;
+
+impl ItemEffect for TrumpCardEffect {
+    fn name(&self) -> &'static str {
+        "Trump Card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Never bet\nagainst the house."
+    }
+
+    fn use_item(&self, _context: &mut ItemContext) -> Vec<ItemAction> {
+        vec![
+            ItemAction::Say("I use my trump card!".into()),
+            ItemAction::Say("Prepare yourself!".into()),
+        ]
+    }
+}
+
+pub struct DoubleDiceEffect;
+
+impl ItemEffect for DoubleDiceEffect {
+    fn name(&self) -> &'static str {
+        "Double Dice"
+    }
+
+    fn description(&self) -> &'static str {
+        "Roll two dice\non your next\nturn."
+    }
+
+    fn use_item(&self, context: &mut ItemContext) -> Vec<ItemAction> {
+        vec![
+            ItemAction::Say(format!("{} will roll two dice!", context.name)),
+            ItemAction::Dice(vec![Die::D6, Die::D6]),
+        ]
+    }
+}
+
+pub struct PickRollEffect;
+
+impl ItemEffect for PickRollEffect {
+    fn name(&self) -> &'static str {
+        "Loaded Die"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pick the number\nyou roll."
+    }
+
+    fn use_item(&self, _context: &mut ItemContext) -> Vec<ItemAction> {
+        vec![
+            ItemAction::Say("A loaded die! Pick your roll.".into()),
+            ItemAction::PickRoll,
+        ]
+    }
+}
+
+pub fn apply_item_actions(
+    actions: Vec<ItemAction>,
+    context: &mut ItemContext,
+    game: &mut Game,
+    players: &mut Players,
+    dialogue: &mut Dialogue,
+    mini_games: &MiniGames,
+    history: &History,
+) -> bool {
+    let mut pick_roll = false;
+    for action in actions {
+        match action {
+            ItemAction::Duel => {
+                game.duel = true;
+                if context.player != Players::HOST {
+                    game.challenger = context.player;
+                }
+            }
+            ItemAction::RevealMiniGame => {
+                let mini_game =
+                    mini_games.next(game, context.ruleset, &history.mini_games(), context.rng);
+                // A duel called later in the round still replaces the revealed game.
+                let text = if game.duel {
+                    format!("The next mini game will be {}.", mini_games.name(mini_game))
+                } else {
+                    format!(
+                        "The next mini game will be {}, unless someone calls a duel.",
+                        mini_games.name(mini_game)
+                    )
+                };
+                dialogue.add(DialogueEntry {
+                    text,
+                    ..Default::default()
+                });
+            }
+            ItemAction::RevealRoll => {
+                let opponent = context.opponent;
+                let turn = if opponent > context.player {
+                    context.turn
+                } else {
+                    context.turn + 1
+                };
+                let player = match players.list.get_mut(opponent) {
+                    Some(player) => player,
+                    None => continue,
+                };
+                // Every die is decided now so the revealed total is the one that gets rolled.
+                let dice = player
+                    .next_dice
+                    .clone()
+                    .filter(|dice| !dice.is_empty())
+                    .unwrap_or_else(|| vec![Die::D6]);
+                let mut next_rolls = player.next_rolls.take().unwrap_or_default();
+                next_rolls.truncate(dice.len());
+                for (index, die) in dice.iter().enumerate().skip(next_rolls.len()) {
+                    next_rolls.push(if index == 0 && context.ruleset.rigged() {
+                        roll(
+                            player.controller,
+                            turn,
+                            context.difficulty,
+                            context.ruleset,
+                            context.rng,
+                        )
+                    } else {
+                        die.roll(&mut *context.rng)
+                    });
+                }
+                let total: u32 = next_rolls.iter().sum();
+                player.next_rolls = Some(next_rolls);
+                dialogue.add(DialogueEntry {
+                    text: format!(
+                        "{} next roll will be a {}.",
+                        players.possessive(opponent),
+                        total
+                    ),
+                    ..Default::default()
+                });
+            }
+            ItemAction::Dice(dice) => {
+                players.list[context.player].next_dice = Some(dice);
+            }
+            ItemAction::PickRoll => {
+                pick_roll = true;
+            }
+            ItemAction::Say(text) => {
+                dialogue.add(DialogueEntry {
+                    text,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    pick_roll
 }
@@ -0,0 +1,259 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use bevy_kira_audio::Audio;
+use std::collections::HashMap;
+
+const AI_THINK_TIME: f32 = 0.75;
+
+pub struct Junction {
+    pub tile: Entity,
+    pub options: Vec<Entity>,
+    pub selected: usize,
+    time: f32,
+    spawned: bool,
+}
+
+impl Junction {
+    pub fn new(tile: Entity, options: Vec<Entity>) -> Self {
+        Self {
+            tile,
+            options,
+            selected: 0,
+            time: 0.,
+            spawned: false,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct JunctionArrow {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct JunctionPrompt;
+
+pub struct JunctionPlugin;
+
+impl Plugin for JunctionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(open.label("junction_open"))
+            .add_system(update.after("junction_open"))
+            .add_system(update_arrows.after("junction_open"));
+    }
+}
+
+pub fn open(
+    mut board: ResMut<Board>,
+    mut commands: Commands,
+    tile_query: Query<&Transform, With<Tile>>,
+    asset_library: Res<AssetLibrary>,
+    players: Res<Players>,
+) {
+    let human = players.is_human(board.current);
+    let junction = if let Some(junction) = &mut board.junction {
+        junction
+    } else {
+        return;
+    };
+    if junction.spawned {
+        return;
+    }
+    junction.spawned = true;
+    let origin = if let Ok(transform) = tile_query.get(junction.tile) {
+        transform.translation.truncate()
+    } else {
+        return;
+    };
+    for (index, option) in junction.options.iter().enumerate() {
+        if let Ok(transform) = tile_query.get(*option) {
+            let difference = transform.translation.truncate() - origin;
+            let position = origin + difference * 0.5;
+            let angle = difference.y.atan2(difference.x);
+            commands
+                .spawn_bundle(Text2dBundle {
+                    text: Text::with_section(
+                        ">",
+                        TextStyle {
+                            font: asset_library.font("game"),
+                            font_size: 32.0,
+                            color: Color::WHITE,
+                        },
+                        TextAlignment {
+                            vertical: VerticalAlign::Center,
+                            horizontal: HorizontalAlign::Center,
+                        },
+                    ),
+                    transform: Transform::from_xyz(position.x, position.y, 0.5)
+                        .with_rotation(Quat::from_rotation_z(angle)),
+                    ..Default::default()
+                })
+                .insert(JunctionArrow { index });
+        }
+    }
+    if human {
+        commands
+            .spawn_bundle(NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Percent(50.0)),
+                    justify_content: JustifyContent::Center,
+                    position_type: PositionType::Absolute,
+                    position: Rect {
+                        bottom: Val::Px(0.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                color: Color::NONE.into(),
+                ..Default::default()
+            })
+            .with_children(|parent| {
+                parent.spawn_bundle(TextBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        ..Default::default()
+                    },
+                    text: Text::with_section(
+                        "ARROWS - Choose Path\nSPACE - Confirm",
+                        TextStyle {
+                            font: asset_library.font("game"),
+                            font_size: 24.0,
+                            color: Color::BLACK,
+                        },
+                        TextAlignment {
+                            horizontal: HorizontalAlign::Center,
+                            vertical: VerticalAlign::Center,
+                        },
+                    ),
+                    ..Default::default()
+                });
+            })
+            .insert(JunctionPrompt);
+    }
+}
+
+pub fn update(
+    mut board: ResMut<Board>,
+    mut commands: Commands,
+    mut input: ResMut<Input<KeyCode>>,
+    tile_query: Query<(Entity, &Tile)>,
+    arrow_query: Query<Entity, With<JunctionArrow>>,
+    prompt_query: Query<Entity, With<JunctionPrompt>>,
+    dialogue: Res<Dialogue>,
+    time: Res<Clock>,
+    audio: Res<Audio>,
+    asset_library: Res<AssetLibrary>,
+    players: Res<Players>,
+) {
+    if dialogue.busy() {
+        return;
+    }
+    let human = players.is_human(board.current);
+    let moves = board.moves;
+    let star = board.star_tile;
+    let junction = if let Some(junction) = &mut board.junction {
+        junction
+    } else {
+        return;
+    };
+    let mut confirmed = false;
+    if !human {
+        junction.time += time.delta_seconds();
+        if junction.time >= AI_THINK_TIME {
+            let tiles: HashMap<Entity, &Tile> = tile_query.iter().collect();
+            junction.selected = ai_choice(&tiles, &junction.options, moves, star);
+            confirmed = true;
+        }
+    } else {
+        let count = junction.options.len();
+        if input.just_pressed(KeyCode::Right) || input.just_pressed(KeyCode::Up) {
+            junction.selected = (junction.selected + 1) % count;
+            audio.play(asset_library.audio("move"));
+        } else if input.just_pressed(KeyCode::Left) || input.just_pressed(KeyCode::Down) {
+            junction.selected = (junction.selected + count - 1) % count;
+            audio.play(asset_library.audio("move"));
+        } else if input.just_pressed(KeyCode::Space) {
+            input.reset(KeyCode::Space);
+            confirmed = true;
+        }
+    }
+    if confirmed {
+        let choice = junction.options[junction.selected];
+        board.junction_choice = Some(choice);
+        board.junction = None;
+        for entity in arrow_query.iter().chain(prompt_query.iter()) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn update_arrows(
+    players: Res<Players>,
+    board: Res<Board>,
+    mut arrow_query: Query<(&mut Text, &mut Transform, &JunctionArrow)>,
+) {
+    let selected = if let Some(junction) = &board.junction {
+        junction.selected
+    } else {
+        return;
+    };
+    let color = players.color(board.current);
+    for (mut text, mut transform, arrow) in arrow_query.iter_mut() {
+        if arrow.index == selected {
+            text.sections[0].style.color = color;
+            transform.scale = Vec3::new(1.5, 1.5, 1.);
+        } else {
+            text.sections[0].style.color = Color::rgba(1., 1., 1., 0.6);
+            transform.scale = Vec3::ONE;
+        }
+    }
+}
+
+fn ai_choice(
+    tiles: &HashMap<Entity, &Tile>,
+    options: &[Entity],
+    moves: u32,
+    star: Option<Entity>,
+) -> usize {
+    let mut best = 0;
+    let mut best_score = i32::MIN;
+    for (index, option) in options.iter().enumerate() {
+        let score = best_landing(tiles, *option, moves, star);
+        if score > best_score {
+            best = index;
+            best_score = score;
+        }
+    }
+    best
+}
+
+fn best_landing(
+    tiles: &HashMap<Entity, &Tile>,
+    entity: Entity,
+    moves: u32,
+    star: Option<Entity>,
+) -> i32 {
+    // Passing the star tile offers a star, so any path through it beats a blue landing.
+    if Some(entity) == star {
+        return 2;
+    }
+    let tile = if let Some(tile) = tiles.get(&entity) {
+        tile
+    } else {
+        return i32::MIN;
+    };
+    if moves > 1 && !tile.next.is_empty() {
+        return tile
+            .next
+            .iter()
+            .map(|next| best_landing(tiles, *next, moves - 1, star))
+            .max()
+            .unwrap_or(i32::MIN);
+    }
+    match tile.tile_type {
+        TileType::Blue => 1,
+        TileType::Green => 0,
+        TileType::Red => -1,
+        _ => 0,
+    }
+}
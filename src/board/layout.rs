@@ -0,0 +1,271 @@
+use super::tile::TileType;
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Deserialize, TypeUuid)]
+#[uuid = "5b0f6c1e-8a4f-4d38-9b1f-2f7c1c7e9a31"]
+pub struct BoardLayout {
+    pub background: String,
+    pub origin: (f32, f32),
+    pub start: usize,
+    pub tiles: Vec<TileLayout>,
+}
+
+#[derive(Deserialize)]
+pub struct TileLayout {
+    pub tile_type: TileType,
+    pub position: (f32, f32),
+    pub next: Vec<usize>,
+    #[serde(default)]
+    pub link: Option<usize>,
+}
+
+#[derive(Debug)]
+pub enum BoardLayoutError {
+    NoTiles,
+    StartOutOfRange {
+        start: usize,
+        tiles: usize,
+    },
+    NoNextTile {
+        tile: usize,
+    },
+    EdgeOutOfRange {
+        tile: usize,
+        edge: usize,
+        target: usize,
+        tiles: usize,
+    },
+    MissingLink {
+        tile: usize,
+    },
+    LinkOutOfRange {
+        tile: usize,
+        target: usize,
+        tiles: usize,
+    },
+}
+
+impl fmt::Display for BoardLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardLayoutError::NoTiles => write!(f, "board has no tiles"),
+            BoardLayoutError::StartOutOfRange { start, tiles } => write!(
+                f,
+                "start tile {} does not exist, the board only has {} tiles",
+                start, tiles
+            ),
+            BoardLayoutError::NoNextTile { tile } => {
+                write!(f, "tile {} has no next tile, pawns would get stuck", tile)
+            }
+            BoardLayoutError::EdgeOutOfRange {
+                tile,
+                edge,
+                target,
+                tiles,
+            } => write!(
+                f,
+                "tile {} edge {} points to tile {}, but the board only has {} tiles",
+                tile, edge, target, tiles
+            ),
+            BoardLayoutError::MissingLink { tile } => {
+                write!(f, "tile {} is a warp tile but has no link", tile)
+            }
+            BoardLayoutError::LinkOutOfRange {
+                tile,
+                target,
+                tiles,
+            } => write!(
+                f,
+                "tile {} links to tile {}, but the board only has {} tiles",
+                tile, target, tiles
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BoardLayoutError {}
+
+impl BoardLayout {
+    pub fn validate(&self) -> Result<(), BoardLayoutError> {
+        let tiles = self.tiles.len();
+        if tiles == 0 {
+            return Err(BoardLayoutError::NoTiles);
+        }
+        if self.start >= tiles {
+            return Err(BoardLayoutError::StartOutOfRange {
+                start: self.start,
+                tiles,
+            });
+        }
+        for (tile, tile_layout) in self.tiles.iter().enumerate() {
+            if tile_layout.next.is_empty() {
+                return Err(BoardLayoutError::NoNextTile { tile });
+            }
+            for (edge, target) in tile_layout.next.iter().enumerate() {
+                if *target >= tiles {
+                    return Err(BoardLayoutError::EdgeOutOfRange {
+                        tile,
+                        edge,
+                        target: *target,
+                        tiles,
+                    });
+                }
+            }
+            match tile_layout.link {
+                Some(target) if target >= tiles => {
+                    return Err(BoardLayoutError::LinkOutOfRange {
+                        tile,
+                        target,
+                        tiles,
+                    });
+                }
+                None if tile_layout.tile_type == TileType::Warp => {
+                    return Err(BoardLayoutError::MissingLink { tile });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut write = |value: u64| {
+            for byte in value.to_le_bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+        write(self.start as u64);
+        write(self.tiles.len() as u64);
+        for tile in self.tiles.iter() {
+            write(tile.tile_type as u64);
+            write(tile.position.0.to_bits() as u64);
+            write(tile.position.1.to_bits() as u64);
+            write(tile.next.len() as u64);
+            for next in tile.next.iter() {
+                write(*next as u64);
+            }
+            write(tile.link.map(|link| link as u64 + 1).unwrap_or(0));
+        }
+        hash
+    }
+
+    pub fn tile_position(&self, index: usize) -> Vec2 {
+        let (x, y) = self.tiles[index].position;
+        Vec2::new(x - self.origin.0, -y + self.origin.1)
+    }
+}
+
+#[derive(Default)]
+pub struct BoardLayoutLoader;
+
+impl AssetLoader for BoardLayoutLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let layout = ron::de::from_bytes::<BoardLayout>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(layout));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["board.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile(tile_type: TileType, next: Vec<usize>, link: Option<usize>) -> TileLayout {
+        TileLayout {
+            tile_type,
+            position: (0., 0.),
+            next,
+            link,
+        }
+    }
+
+    fn ring() -> BoardLayout {
+        BoardLayout {
+            background: "bg".into(),
+            origin: (0., 0.),
+            start: 0,
+            tiles: vec![
+                tile(TileType::Blue, vec![1], None),
+                tile(TileType::Red, vec![2, 0], None),
+                tile(TileType::Warp, vec![0], Some(1)),
+            ],
+        }
+    }
+
+    #[test]
+    fn valid_layout() {
+        assert!(ring().validate().is_ok());
+    }
+
+    #[test]
+    fn default_board_is_valid() {
+        let layout: BoardLayout =
+            ron::de::from_str(include_str!("../../assets/boards/default.board.ron")).unwrap();
+        assert!(layout.validate().is_ok());
+    }
+
+    #[test]
+    fn bad_next_edge() {
+        let mut layout = ring();
+        layout.tiles[1].next = vec![2, 7];
+        assert!(matches!(
+            layout.validate(),
+            Err(BoardLayoutError::EdgeOutOfRange {
+                tile: 1,
+                edge: 1,
+                target: 7,
+                tiles: 3,
+            })
+        ));
+    }
+
+    #[test]
+    fn bad_start() {
+        let mut layout = ring();
+        layout.start = 3;
+        assert!(matches!(
+            layout.validate(),
+            Err(BoardLayoutError::StartOutOfRange { start: 3, tiles: 3 })
+        ));
+    }
+
+    #[test]
+    fn warp_without_link() {
+        let mut layout = ring();
+        layout.tiles[2].link = None;
+        assert!(matches!(
+            layout.validate(),
+            Err(BoardLayoutError::MissingLink { tile: 2 })
+        ));
+    }
+
+    #[test]
+    fn fingerprint_follows_layout() {
+        assert_eq!(ring().fingerprint(), ring().fingerprint());
+        let mut layout = ring();
+        layout.tiles[1].next = vec![0, 2];
+        assert_ne!(layout.fingerprint(), ring().fingerprint());
+        let mut layout = ring();
+        layout.tiles[2].link = Some(0);
+        assert_ne!(layout.fingerprint(), ring().fingerprint());
+        let mut layout = ring();
+        layout.background = "other".into();
+        assert_eq!(layout.fingerprint(), ring().fingerprint());
+    }
+}
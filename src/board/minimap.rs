@@ -0,0 +1,216 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+const MAP_SIZE: f32 = 160.;
+const MAP_PADDING: f32 = 12.;
+const TILE_DOT: f32 = 6.;
+const SHOP_DOT: f32 = 10.;
+const PAWN_DOT: f32 = 9.;
+const BACKGROUND_COLOR: Color = Color::rgba(1., 1., 1., 0.75);
+
+#[derive(Component)]
+pub struct Minimap;
+
+#[derive(Component)]
+pub struct MinimapTile;
+
+#[derive(Component)]
+pub struct MinimapPawn {
+    player: usize,
+}
+
+#[derive(Default)]
+pub struct MinimapLayout {
+    tiles: Vec<Entity>,
+    min: Vec2,
+    size: Vec2,
+}
+
+impl MinimapLayout {
+    fn project(&self, position: Vec2, dot: f32) -> Rect<Val> {
+        let inner = MAP_SIZE - MAP_PADDING * 2.;
+        let scale = inner / self.size.max_element().max(1.);
+        let centering = (Vec2::splat(inner) - self.size * scale) / 2.;
+        let point = (position - self.min) * scale + centering + Vec2::splat(MAP_PADDING);
+        Rect {
+            left: Val::Px(point.x - dot / 2.),
+            bottom: Val::Px(point.y - dot / 2.),
+            ..Default::default()
+        }
+    }
+}
+
+fn tile_color(tile_type: TileType) -> Color {
+    match tile_type {
+        TileType::Blue => Color::rgb(0.2, 0.4, 0.9),
+        TileType::Red => Color::rgb(0.9, 0.2, 0.2),
+        TileType::Green => Color::rgb(0.1, 0.7, 0.2),
+        TileType::Event => Color::rgb(0.9, 0.7, 0.1),
+        TileType::Warp => Color::rgb(0.6, 0.3, 0.8),
+        TileType::Bank => Color::rgb(0.5, 0.5, 0.5),
+        TileType::Duel => Color::rgb(0.2, 0.2, 0.2),
+    }
+}
+
+fn dot_style(position: Rect<Val>, size: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        position,
+        size: Size::new(Val::Px(size), Val::Px(size)),
+        ..Default::default()
+    }
+}
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MinimapLayout>()
+            .add_system(init)
+            .add_system(toggle)
+            .add_system(build_tiles)
+            .add_system(update);
+    }
+}
+
+pub fn init(mut commands: Commands, mut asset_library_ready: EventReader<AssetLibraryReady>) {
+    for _ in asset_library_ready.iter() {
+        commands
+            .spawn_bundle(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: Rect {
+                        right: Val::Px(8.),
+                        bottom: Val::Px(8.),
+                        ..Default::default()
+                    },
+                    size: Size::new(Val::Px(MAP_SIZE), Val::Px(MAP_SIZE)),
+                    ..Default::default()
+                },
+                visibility: Visibility { is_visible: false },
+                color: BACKGROUND_COLOR.into(),
+                ..Default::default()
+            })
+            .insert(Minimap)
+            .with_children(|parent| {
+                for player in 0..MAX_PLAYERS {
+                    parent
+                        .spawn_bundle(NodeBundle {
+                            style: dot_style(Rect::default(), PAWN_DOT),
+                            visibility: Visibility { is_visible: false },
+                            ..Default::default()
+                        })
+                        .insert(MinimapPawn { player });
+                }
+            });
+    }
+}
+
+pub fn toggle(
+    mut board: ResMut<Board>,
+    input: Res<Input<KeyCode>>,
+    game_state: Res<State<GameState>>,
+) {
+    if *game_state.current() == GameState::Board
+        && board.score_overlay
+        && input.just_pressed(KeyCode::M)
+    {
+        board.minimap = !board.minimap;
+    }
+}
+
+pub fn build_tiles(
+    mut commands: Commands,
+    board: Res<Board>,
+    mut layout: ResMut<MinimapLayout>,
+    minimap_query: Query<Entity, With<Minimap>>,
+    dot_query: Query<Entity, With<MinimapTile>>,
+    tile_query: Query<(&Tile, &Transform)>,
+) {
+    if board.tiles == layout.tiles {
+        return;
+    }
+    let minimap = if let Some(minimap) = minimap_query.iter().next() {
+        minimap
+    } else {
+        return;
+    };
+    let tiles: Vec<(&Tile, Vec2)> = board
+        .tiles
+        .iter()
+        .filter_map(|entity| tile_query.get(*entity).ok())
+        .map(|(tile, transform)| (tile, transform.translation.truncate()))
+        .collect();
+    if tiles.len() != board.tiles.len() {
+        return;
+    }
+    for entity in dot_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let min = tiles
+        .iter()
+        .fold(Vec2::splat(f32::MAX), |min, (_, position)| {
+            min.min(*position)
+        });
+    let max = tiles
+        .iter()
+        .fold(Vec2::splat(f32::MIN), |max, (_, position)| {
+            max.max(*position)
+        });
+    layout.tiles = board.tiles.clone();
+    layout.min = min;
+    layout.size = (max - min).max(Vec2::ZERO);
+    let dots: Vec<Entity> = tiles
+        .iter()
+        .map(|(tile, position)| {
+            let size = if tile.tile_type == TileType::Green {
+                SHOP_DOT
+            } else {
+                TILE_DOT
+            };
+            commands
+                .spawn_bundle(NodeBundle {
+                    style: dot_style(layout.project(*position, size), size),
+                    visibility: Visibility { is_visible: false },
+                    color: tile_color(tile.tile_type).into(),
+                    ..Default::default()
+                })
+                .insert(MinimapTile)
+                .id()
+        })
+        .collect();
+    commands.entity(minimap).insert_children(0, &dots);
+}
+
+pub fn update(
+    board: Res<Board>,
+    players: Res<Players>,
+    layout: Res<MinimapLayout>,
+    game_state: Res<State<GameState>>,
+    pawn_query: Query<&Transform, With<Pawn>>,
+    mut queries: QuerySet<(
+        QueryState<&mut Visibility, Or<(With<Minimap>, With<MinimapTile>)>>,
+        QueryState<(&mut Visibility, &mut Style, &mut UiColor, &MinimapPawn)>,
+    )>,
+) {
+    let visible = *game_state.current() == GameState::Board
+        && board.score_overlay
+        && board.minimap
+        && !layout.tiles.is_empty();
+    for mut visibility in queries.q0().iter_mut() {
+        visibility.is_visible = visible;
+    }
+    for (mut visibility, mut style, mut color, minimap_pawn) in queries.q1().iter_mut() {
+        let transform = players
+            .list
+            .get(minimap_pawn.player)
+            .and_then(|player| player.pawn)
+            .and_then(|pawn| pawn_query.get(pawn).ok());
+        let transform = transform.filter(|_| visible);
+        visibility.is_visible = transform.is_some();
+        if let Some(transform) = transform {
+            style.position = layout.project(transform.translation.truncate(), PAWN_DOT);
+            color.0 = players.color(minimap_pawn.player);
+        }
+    }
+}
@@ -1,52 +1,72 @@
 use crate::prelude::*;
 use bevy::prelude::*;
+use catalog::ShopCatalogLoader;
 use end_game::EndGamePlugin;
 use ending::EndingPlugin;
 use free_cam::FreeCamPlugin;
-use item::Item;
+use history_panel::HistoryPanelPlugin;
+use junction::{Junction, JunctionPlugin};
+use layout::BoardLayoutLoader;
+use minimap::MinimapPlugin;
 use moving::MovingPlugin;
 use pawn::{Pawn, PawnPlugin};
+use preview::PreviewPlugin;
 use score_overlay::ScoreOverlayPlugin;
 use shop::ShopPlugin;
+use star::StarPlugin;
 use starting::StartingPlugin;
-use tile::{tiles, Tile, TileType};
+use std::collections::HashMap;
+use tile::{Tile, TileType};
+use tile_effect::TileEffects;
 use turn_input::TurnInputPlugin;
 use turn_intro::TurnIntroPlugin;
 use use_item::UseItemPlugin;
 
 pub struct Board {
-    pub my_turn: bool,
+    pub current: usize,
     pub score_overlay: bool,
-    pub my_pawn: Option<Entity>,
-    pub your_pawn: Option<Entity>,
+    pub minimap: bool,
+    pub start_tile: Option<Entity>,
+    pub tiles: Vec<Entity>,
+    pub layout_error: Option<String>,
+    pub background: Option<Handle<Image>>,
     pub active_pawn: Option<Entity>,
     pub moving: bool,
     pub shop: bool,
     pub first_shop: bool,
+    pub shop_sold: HashMap<Item, u32>,
+    pub star_tile: Option<Entity>,
+    pub star_offer: bool,
+    pub final_stretch: bool,
     pub moves: u32,
-    pub my_item: Item,
-    pub your_item: Item,
-    pub my_item_use_interpolate: f32,
-    pub your_item_use_interpolate: f32,
+    pub bank: u32,
+    pub junction: Option<Junction>,
+    pub junction_choice: Option<Entity>,
     pub rapier_dialog: bool,
 }
 
 impl Default for Board {
     fn default() -> Self {
         Self {
-            my_turn: true,
+            current: Players::HOST,
             score_overlay: false,
-            my_pawn: None,
-            your_pawn: None,
+            minimap: true,
+            start_tile: None,
+            tiles: vec![],
+            layout_error: None,
+            background: None,
             active_pawn: None,
             moving: false,
             shop: false,
             first_shop: true,
+            shop_sold: HashMap::new(),
+            star_tile: None,
+            star_offer: false,
+            final_stretch: false,
             moves: 3,
-            my_item: Item::TrumpCard,
-            your_item: Item::None,
-            my_item_use_interpolate: 0.,
-            your_item_use_interpolate: 0.,
+            bank: 0,
+            junction: None,
+            junction_choice: None,
             rapier_dialog: true,
         }
     }
@@ -76,12 +96,23 @@ impl Plugin for BoardPlugin {
             .add_plugin(MovingPlugin)
             .add_plugin(EndingPlugin)
             .add_plugin(ScoreOverlayPlugin)
+            .add_plugin(MinimapPlugin)
+            .add_plugin(HistoryPanelPlugin)
             .add_plugin(ShopPlugin)
+            .add_plugin(StarPlugin)
             .add_plugin(PawnPlugin)
+            .add_plugin(PreviewPlugin)
+            .add_plugin(JunctionPlugin)
             .add_plugin(UseItemPlugin)
             .add_plugin(EndGamePlugin)
+            .add_asset::<BoardLayout>()
+            .init_asset_loader::<BoardLayoutLoader>()
+            .add_asset::<ShopCatalog>()
+            .init_asset_loader::<ShopCatalogLoader>()
             .add_state(BoardState::Inactive)
             .insert_resource(Board::default())
+            .init_resource::<TileEffects>()
+            .init_resource::<ItemEffects>()
             .add_system_set(SystemSet::on_enter(GameState::Board).with_system(enter))
             .add_system_set(SystemSet::on_exit(GameState::Board).with_system(exit))
             .add_system(init)
@@ -93,89 +124,153 @@ pub fn init(
     mut board: ResMut<Board>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    board_layouts: Res<Assets<BoardLayout>>,
     mut asset_library_ready: EventReader<AssetLibraryReady>,
 ) {
     for _ in asset_library_ready.iter() {
+        let layout = if let Some(layout) = board_layouts.get(asset_library.board("default")) {
+            layout
+        } else {
+            board.layout_error = Some("the board failed to load".into());
+            error!("board layout \"default\" failed to load");
+            continue;
+        };
+        if let Err(err) = layout.validate() {
+            board.layout_error = Some(err.to_string());
+            error!("board layout \"default\" is invalid: {}", err);
+            continue;
+        }
+        if !asset_library.has_image(&layout.background) {
+            board.layout_error = Some(format!(
+                "the board uses unknown background image \"{}\"",
+                layout.background
+            ));
+            error!(
+                "board layout \"default\" uses unknown background image \"{}\"",
+                layout.background
+            );
+            continue;
+        }
+        board.layout_error = None;
+        board.background = Some(asset_library.image(&layout.background));
         commands
             .spawn_bundle(SpriteBundle {
-                texture: asset_library.image("bg"),
+                texture: asset_library.image(&layout.background),
                 transform: Transform::from_xyz(0.0, 0.0, 0.0),
                 visibility: Visibility { is_visible: false },
                 ..Default::default()
             })
             .insert(SceneVisibility(GameState::Board));
-        let tiles = tiles();
         let mut entities = vec![];
-        for _ in tiles.iter() {
+        for _ in layout.tiles.iter() {
             entities.push(commands.spawn().id());
         }
-        for (i, tile_def) in tiles.iter().enumerate() {
-            let next = if i == entities.len() - 1 {
-                vec![entities[1]]
-            } else {
-                vec![entities[i + 1]]
-            };
+        for (i, tile_layout) in layout.tiles.iter().enumerate() {
+            let position = layout.tile_position(i);
+            let next = tile_layout
+                .next
+                .iter()
+                .map(|next| entities[*next])
+                .collect();
             commands
                 .entity(entities[i])
                 .insert_bundle(SpriteBundle {
-                    texture: asset_library.image(match tile_def.tile_type {
-                        TileType::Blue => "tile_blue",
-                        TileType::Red => "tile_red",
-                        TileType::Green => "tile_green",
-                    }),
+                    texture: asset_library.image(tile_layout.tile_type.image()),
                     visibility: Visibility { is_visible: false },
-                    transform: Transform::from_xyz(tile_def.position.x, tile_def.position.y, 0.1),
+                    transform: Transform::from_xyz(position.x, position.y, 0.1),
                     ..Default::default()
                 })
                 .insert(Tile {
-                    tile_type: tile_def.tile_type,
+                    tile_type: tile_layout.tile_type,
                     next,
+                    link: tile_layout.link.map(|link| entities[link]),
                 })
                 .insert(SceneVisibility(GameState::Board));
         }
-        let my_pawn = commands
-            .spawn_bundle(SpriteBundle {
-                texture: asset_library.image("pawn"),
-                visibility: Visibility { is_visible: false },
-                transform: Transform::from_xyz(tiles[0].position.x, tiles[0].position.y, 0.41),
-                ..Default::default()
-            })
-            .insert(Pawn {
-                tile: entities[0],
-                tile_type: TileType::Blue,
-                player: false,
-                first_dec: true,
-            })
-            .insert(SceneVisibility(GameState::Board))
-            .id();
-        let your_pawn = commands
-            .spawn_bundle(SpriteBundle {
-                texture: asset_library.image("pawn"),
-                visibility: Visibility { is_visible: false },
-                transform: Transform::from_xyz(tiles[0].position.x, tiles[0].position.y, 0.4),
-                ..Default::default()
-            })
-            .insert(Pawn {
-                tile: entities[0],
-                tile_type: TileType::Blue,
-                player: true,
-                first_dec: true,
-            })
-            .insert(SceneVisibility(GameState::Board))
-            .id();
-        board.my_pawn = Some(my_pawn);
-        board.your_pawn = Some(your_pawn);
+        board.start_tile = Some(entities[layout.start]);
+        board.tiles = entities;
     }
 }
 
-pub fn enter(mut board: ResMut<Board>, mut board_state: ResMut<State<BoardState>>) {
-    board.my_turn = true;
+pub fn enter(
+    mut board: ResMut<Board>,
+    mut board_state: ResMut<State<BoardState>>,
+    mut players: ResMut<Players>,
+    mut commands: Commands,
+    tile_query: Query<&Transform, With<Tile>>,
+    asset_library: Res<AssetLibrary>,
+    images: Res<Assets<Image>>,
+    mut camera_controller: ResMut<CameraController>,
+    game: Res<Game>,
+    mode: Res<GameMode>,
+) {
+    camera_controller.bounds = board
+        .background
+        .as_ref()
+        .and_then(|background| images.get(background))
+        .map(|image| {
+            let half_size = image.size() / 2.;
+            CameraBounds {
+                min: -half_size,
+                max: half_size,
+            }
+        });
+    if let Some(start_tile) = board.start_tile {
+        for (index, player) in players.list.iter_mut().enumerate() {
+            if player.pawn.is_some() {
+                continue;
+            }
+            let tile = player
+                .spawn_tile
+                .take()
+                .and_then(|tile| board.tiles.get(tile).copied())
+                .unwrap_or(start_tile);
+            let start_position = if let Ok(transform) = tile_query.get(tile) {
+                transform.translation.truncate()
+            } else {
+                Vec2::ZERO
+            };
+            let pawn = commands
+                .spawn_bundle(SpriteBundle {
+                    texture: asset_library.image("pawn"),
+                    visibility: Visibility { is_visible: false },
+                    transform: Transform::from_xyz(
+                        start_position.x,
+                        start_position.y,
+                        0.41 - index as f32 * 0.01,
+                    ),
+                    ..Default::default()
+                })
+                .insert(Pawn {
+                    tile,
+                    tile_type: TileType::Blue,
+                    player: index,
+                    first_dec: true,
+                })
+                .insert(SceneVisibility(GameState::Board))
+                .id();
+            player.pawn = Some(pawn);
+        }
+    }
+    board.current = Players::HOST;
     board.score_overlay = true;
-    board.active_pawn = board.my_pawn;
-    board_state.set(BoardState::Starting).unwrap();
+    board.active_pawn = players
+        .list
+        .get(Players::HOST)
+        .and_then(|player| player.pawn);
+    if mode.over(game.turn) {
+        board_state.set(BoardState::EndGame).unwrap();
+    } else {
+        board_state.set(BoardState::Starting).unwrap();
+    }
 }
 
-pub fn exit(mut board: ResMut<Board>, mut board_state: ResMut<State<BoardState>>) {
+pub fn exit(
+    mut board: ResMut<Board>,
+    mut board_state: ResMut<State<BoardState>>,
+    mut camera_controller: ResMut<CameraController>,
+) {
+    camera_controller.bounds = None;
     board.score_overlay = false;
     board_state.set(BoardState::Inactive).unwrap();
 }
@@ -186,25 +281,38 @@ pub fn reset(mut reset: EventReader<GameReset>, mut board: ResMut<Board>) {
     }
 }
 
+mod catalog;
 mod end_game;
 mod ending;
 mod free_cam;
+mod history_panel;
 mod item;
+mod junction;
+mod layout;
+mod minimap;
 mod moving;
 mod pawn;
+mod preview;
 mod score_overlay;
 mod shop;
+mod star;
 mod starting;
 mod tile;
+mod tile_effect;
 mod turn_input;
 mod turn_intro;
 mod use_item;
 
 pub mod prelude {
     pub use super::{
-        item::Item,
+        catalog::ShopCatalog,
+        item::{apply_item_actions, Item, ItemAction, ItemContext, ItemEffect, ItemEffects},
+        junction::Junction,
+        layout::BoardLayout,
+        pawn::Pawn,
         shop::ShopOpen,
         tile::{Tile, TileType},
+        tile_effect::{apply_tile_actions, TileAction, TileContext, TileEffect, TileEffects},
         Board, BoardState,
     };
 }
@@ -1,10 +1,13 @@
 use super::pawn::Pawn;
 use crate::prelude::*;
 use bevy::prelude::*;
-use rand::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
 
 pub struct Moving {
     sent_dialogue: bool,
+    landed: bool,
+    rolled: bool,
 }
 
 pub struct MovingPlugin;
@@ -13,6 +16,8 @@ impl Plugin for MovingPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(Moving {
             sent_dialogue: false,
+            landed: false,
+            rolled: false,
         })
         .add_system_set(SystemSet::on_enter(BoardState::Moving).with_system(init))
         .add_system_set(SystemSet::on_update(BoardState::Moving).with_system(update));
@@ -25,27 +30,67 @@ pub fn init(
     mut moving: ResMut<Moving>,
     mut dice_start: EventWriter<DiceRollStart>,
     difficulty: Res<Difficulty>,
+    mut players: ResMut<Players>,
+    ruleset: Res<Ruleset>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     moving.sent_dialogue = false;
-    board.moving = true;
-    let mut rng = rand::thread_rng();
-    if board.my_turn {
-        if game.turn == 1 {
-            board.moves = 5;
-        } else {
-            board.moves = if game.turn % 2 == 1 { 4 } else { 5 };
-        }
-    } else {
-        match *difficulty {
-            Difficulty::Normal => {
-                board.moves = 6;
-            }
-            Difficulty::Hard => {
-                board.moves = rng.gen_range(1..=2) * 3;
+    moving.landed = false;
+    moving.rolled = false;
+    board.moving = false;
+    let player = &mut players.list[board.current];
+    let dice = player
+        .next_dice
+        .take()
+        .filter(|dice| !dice.is_empty())
+        .unwrap_or_else(|| vec![Die::D6]);
+    let mut overrides = vec![None; dice.len()];
+    let next_rolls = player.next_rolls.take().unwrap_or_default();
+    for (forced, value) in overrides.iter_mut().zip(next_rolls) {
+        *forced = Some(value);
+    }
+    if overrides[0].is_none() && ruleset.rigged() {
+        overrides[0] = Some(roll(
+            player.controller,
+            game.turn,
+            &difficulty,
+            &ruleset,
+            game_rng.stream(RngStream::Dice),
+        ));
+    }
+    dice_start.send(DiceRollStart {
+        dice,
+        overrides,
+        interactive: player.controller == Controller::Human,
+    });
+}
+
+pub fn roll(
+    controller: Controller,
+    turn: u32,
+    difficulty: &Difficulty,
+    ruleset: &Ruleset,
+    rng: &mut StdRng,
+) -> u32 {
+    if !ruleset.rigged() {
+        return rng.gen_range(1..=6);
+    }
+    match controller {
+        Controller::Host => {
+            if turn == 1 {
+                5
+            } else if turn % 2 == 1 {
+                4
+            } else {
+                5
             }
         }
+        Controller::Human => match *difficulty {
+            Difficulty::Normal => 6,
+            Difficulty::Hard => rng.gen_range(1..=2) * 3,
+        },
+        Controller::Cpu => rng.gen_range(1..=6),
     }
-    dice_start.send(DiceRollStart { value: board.moves });
 }
 
 pub fn update(
@@ -53,11 +98,36 @@ pub fn update(
     mut board: ResMut<Board>,
     mut board_state: ResMut<State<BoardState>>,
     mut dialogue: ResMut<Dialogue>,
-    pawn_query: Query<&Pawn>,
+    mut pawn_query: Query<(&mut Pawn, &mut Transform)>,
+    tile_query: Query<(&Tile, &Transform), Without<Pawn>>,
     mut moving: ResMut<Moving>,
     difficulty: Res<Difficulty>,
+    tile_effects: Res<TileEffects>,
+    mut shop_open: EventWriter<ShopOpen>,
+    mut players: CoinLedger,
+    ruleset: Res<Ruleset>,
+    mut game_rng: ResMut<GameRng>,
+    mut dice_result: EventReader<DiceRollResult>,
+    mut history: ResMut<History>,
+    mode: Res<GameMode>,
 ) {
-    if !board.moving && !moving.sent_dialogue && !board.my_turn {
+    for result in dice_result.iter() {
+        history.record(
+            game.turn,
+            HistoryEvent::Rolled {
+                player: board.current,
+                total: result.total,
+            },
+        );
+        board.moves = result.total;
+        board.moving = true;
+        moving.rolled = true;
+    }
+    if !moving.rolled {
+        return;
+    }
+    if !board.moving && !moving.sent_dialogue && ruleset.rigged() && players.is_human(board.current)
+    {
         if game.turn == 1 {
             if matches!(*difficulty, Difficulty::Normal) {
                 dialogue.add(DialogueEntry {
@@ -70,10 +140,12 @@ pub fn update(
                     ..Default::default()
                 });
             }
-            dialogue.add(DialogueEntry {
-                text: "If you run out of coins, you lose the game!".into(),
-                ..Default::default()
-            });
+            if !mode.stars() {
+                dialogue.add(DialogueEntry {
+                    text: "If you run out of coins, you lose the game!".into(),
+                    ..Default::default()
+                });
+            }
         } else if game.turn == 2 {
             dialogue.add(DialogueEntry {
                 text: "Another red! Too Bad!".into(),
@@ -91,69 +163,60 @@ pub fn update(
         }
         moving.sent_dialogue = true;
     }
-    if !board.moving && !dialogue.busy() {
-        if board.my_turn {
-            if let Some(my_pawn) = board.my_pawn {
-                if let Ok(pawn) = pawn_query.get(my_pawn) {
-                    match pawn.tile_type {
-                        TileType::Blue => {
-                            if matches!(*difficulty, Difficulty::Normal) {
-                                game.my_coins += 1;
-                            } else {
-                                game.my_coins += 3;
-                            }
-                        }
-                        TileType::Red => {
-                            if matches!(*difficulty, Difficulty::Normal) {
-                                if game.my_coins <= 1 {
-                                    game.my_coins = 0;
-                                } else {
-                                    game.my_coins -= 1;
-                                }
-                            } else {
-                                if game.my_coins <= 3 {
-                                    game.my_coins = 0;
-                                } else {
-                                    game.my_coins -= 3;
-                                }
+    if !board.moving && !dialogue.busy() && !board.shop && !board.star_offer {
+        if !moving.landed {
+            moving.landed = true;
+            if let Some(active_pawn) = board.active_pawn {
+                if let Ok((mut pawn, mut transform)) = pawn_query.get_mut(active_pawn) {
+                    let mut context = TileContext::new(
+                        board.current,
+                        &players,
+                        &board,
+                        &difficulty,
+                        game_rng.stream(RngStream::Board),
+                    );
+                    let actions = tile_effects.land(pawn.tile_type, &mut context);
+                    history.record(
+                        game.turn,
+                        HistoryEvent::Landed {
+                            player: board.current,
+                            tile: pawn.tile_type,
+                        },
+                    );
+                    let warp = apply_tile_actions(
+                        actions,
+                        board.current,
+                        &mut game,
+                        &mut players,
+                        &mut board,
+                        &mut dialogue,
+                        &mut shop_open,
+                    );
+                    if board.star_tile == Some(pawn.tile) {
+                        board.star_offer = true;
+                    }
+                    if warp {
+                        let link = tile_query
+                            .get(pawn.tile)
+                            .ok()
+                            .and_then(|(tile, _)| tile.link);
+                        if let Some(link) = link {
+                            if let Ok((tile, tile_transform)) = tile_query.get(link) {
+                                pawn.tile = link;
+                                pawn.tile_type = tile.tile_type;
+                                transform.translation.x = tile_transform.translation.x;
+                                transform.translation.y = tile_transform.translation.y;
                             }
                         }
-                        TileType::Green => {}
                     }
                 }
             }
-            board.my_turn = false;
+            return;
+        }
+        board.current += 1;
+        if board.current < players.len() {
             board_state.set(BoardState::TurnIntro).unwrap();
         } else {
-            if let Some(your_pawn) = board.your_pawn {
-                if let Ok(pawn) = pawn_query.get(your_pawn) {
-                    match pawn.tile_type {
-                        TileType::Blue => {
-                            if matches!(*difficulty, Difficulty::Normal) {
-                                game.your_coins += 1;
-                            } else {
-                                game.your_coins += 3;
-                            }
-                        }
-                        TileType::Red => {
-                            if matches!(*difficulty, Difficulty::Normal) {
-                                if game.your_coins <= 1 {
-                                    game.your_coins = 0;
-                                } else {
-                                    game.your_coins -= 1;
-                                }
-                            } else {
-                                if game.your_coins <= 3 {
-                                    game.your_coins = 0;
-                                } else {
-                                    game.your_coins -= 3;
-                                }
-                            }
-                        }
-                        TileType::Green => {}
-                    }
-                }
-            }
             board_state.set(BoardState::Ending).unwrap();
         }
     }
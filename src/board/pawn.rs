@@ -7,7 +7,7 @@ use std::collections::HashMap;
 pub struct Pawn {
     pub tile: Entity,
     pub tile_type: TileType,
-    pub player: bool,
+    pub player: usize,
     pub first_dec: bool,
 }
 
@@ -20,18 +20,23 @@ impl Plugin for PawnPlugin {
 }
 
 pub fn pawn_move(
-    game: Res<Game>,
+    mut game: ResMut<Game>,
     mut board: ResMut<Board>,
     mut queries: QuerySet<(
         QueryState<(Entity, &Transform, &Tile)>,
         QueryState<(Entity, &mut Pawn, &mut Transform)>,
     )>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut shop_open: EventWriter<ShopOpen>,
     mut dice_value: EventWriter<DiceRollValue>,
     mut dice_hide: EventWriter<DiceRollHide>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    mut dialogue: ResMut<Dialogue>,
+    tile_effects: Res<TileEffects>,
+    difficulty: Res<Difficulty>,
+    mut players: CoinLedger,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let tile_info: HashMap<Entity, (Vec2, Vec<Entity>, TileType)> = queries
         .q0()
@@ -44,23 +49,57 @@ pub fn pawn_move(
         } else {
             false
         };
-        if board.moving && !game.dice_roll && !board.shop && is_active {
+        if board.moving
+            && !game.dice_roll
+            && !board.shop
+            && !board.star_offer
+            && board.junction.is_none()
+            && !dialogue.busy()
+            && is_active
+        {
             if let Some((target_position, next_tiles, tile_type)) = tile_info.get(&pawn.tile) {
                 let mut position = pawn_transform.translation.truncate();
                 let difference = *target_position - position;
                 let magnitude = difference.length();
                 let speed = time.delta_seconds() * 200.;
-                if magnitude < speed {
-                    if matches!(*tile_type, TileType::Green) && !board.my_turn {
-                        board.shop = true;
-                        shop_open.send(ShopOpen);
+                if magnitude < speed
+                    && board.moves > 0
+                    && next_tiles.len() > 1
+                    && board.junction_choice.is_none()
+                {
+                    board.junction = Some(Junction::new(pawn.tile, next_tiles.clone()));
+                    position = *target_position;
+                } else if magnitude < speed {
+                    if pawn.first_dec {
+                        pawn.first_dec = false;
+                    } else if board.moves > 0 {
+                        let mut context = TileContext::new(
+                            board.current,
+                            &players,
+                            &board,
+                            &difficulty,
+                            game_rng.stream(RngStream::Board),
+                        );
+                        let actions = tile_effects.pass(*tile_type, &mut context);
+                        apply_tile_actions(
+                            actions,
+                            board.current,
+                            &mut game,
+                            &mut players,
+                            &mut board,
+                            &mut dialogue,
+                            &mut shop_open,
+                        );
+                        if board.star_tile == Some(pawn.tile) {
+                            board.star_offer = true;
+                        }
                     }
                     if board.moves > 0 {
                         audio.play(asset_library.audio("move"));
                         dice_value.send(DiceRollValue { value: board.moves });
                         board.moves -= 1;
                         position = *target_position;
-                        pawn.tile = next_tiles[0];
+                        pawn.tile = board.junction_choice.take().unwrap_or(next_tiles[0]);
                     } else {
                         audio.play(asset_library.audio("move"));
                         dice_hide.send(DiceRollHide);
@@ -74,18 +113,14 @@ pub fn pawn_move(
                 pawn_transform.translation.x = position.x;
                 pawn_transform.translation.y = position.y;
             }
-        } else {
+        } else if !is_active || !board.moving {
             pawn.first_dec = true;
         }
     }
 }
 
-pub fn pawn_color(game: Res<Game>, mut query: Query<(&mut Sprite, &Pawn)>) {
+pub fn pawn_color(players: Res<Players>, mut query: Query<(&mut Sprite, &Pawn)>) {
     for (mut sprite, pawn) in query.iter_mut() {
-        if pawn.player {
-            sprite.color = game.your_color;
-        } else {
-            sprite.color = game.my_color;
-        }
+        sprite.color = players.color(pawn.player);
     }
 }
@@ -0,0 +1,180 @@
+use super::pawn::Pawn;
+use crate::prelude::*;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+const PATH_COLOR: Color = Color::rgb(1.0, 1.0, 0.6);
+const END_COLOR: Color = Color::rgb(1.0, 0.85, 0.3);
+
+#[derive(Component)]
+pub struct PreviewLabel;
+
+#[derive(Default)]
+pub struct MovePreview {
+    path: HashSet<Entity>,
+    ends: Vec<Entity>,
+}
+
+pub struct PreviewPlugin;
+
+impl Plugin for PreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MovePreview>().add_system(update);
+    }
+}
+
+pub fn reachable(
+    start: Entity,
+    moves: u32,
+    next: &HashMap<Entity, Vec<Entity>>,
+) -> (HashSet<Entity>, Vec<Entity>) {
+    let mut path = HashSet::new();
+    let mut frontier = vec![start];
+    for _ in 0..moves {
+        let mut step = vec![];
+        for tile in frontier.iter() {
+            for next_tile in next.get(tile).into_iter().flatten() {
+                if !step.contains(next_tile) {
+                    step.push(*next_tile);
+                }
+            }
+        }
+        path.extend(step.iter().copied());
+        frontier = step;
+    }
+    (path, frontier)
+}
+
+pub fn update(
+    mut commands: Commands,
+    board: Res<Board>,
+    board_state: Res<State<BoardState>>,
+    mut preview: ResMut<MovePreview>,
+    pawn_query: Query<&Pawn>,
+    mut tile_query: Query<(Entity, &Tile, &Transform, &mut Sprite)>,
+    label_query: Query<Entity, With<PreviewLabel>>,
+    tile_effects: Res<TileEffects>,
+    difficulty: Res<Difficulty>,
+    asset_library: Res<AssetLibrary>,
+) {
+    let start = board
+        .active_pawn
+        .and_then(|pawn| pawn_query.get(pawn).ok())
+        .map(|pawn| pawn.tile);
+    let (path, ends) = match start {
+        Some(start) if *board_state.current() == BoardState::Moving && board.moving => {
+            let next = tile_query
+                .iter()
+                .map(|(entity, tile, _, _)| (entity, tile.next.clone()))
+                .collect();
+            reachable(start, board.moves, &next)
+        }
+        _ => (HashSet::new(), vec![]),
+    };
+    if path == preview.path && ends == preview.ends {
+        return;
+    }
+    for entity in label_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    for entity in preview.path.iter().chain(preview.ends.iter()) {
+        if path.contains(entity) || ends.contains(entity) {
+            continue;
+        }
+        if let Ok((_, _, _, mut sprite)) = tile_query.get_mut(*entity) {
+            sprite.color = Color::WHITE;
+        }
+    }
+    for entity in path.iter() {
+        if let Ok((_, _, _, mut sprite)) = tile_query.get_mut(*entity) {
+            sprite.color = PATH_COLOR;
+        }
+    }
+    for entity in ends.iter() {
+        let (_, tile, transform, mut sprite) = match tile_query.get_mut(*entity) {
+            Ok(tile) => tile,
+            Err(_) => continue,
+        };
+        sprite.color = END_COLOR;
+        if let Some(text) = tile_effects.preview(tile.tile_type, &difficulty, board.final_stretch) {
+            commands
+                .spawn_bundle(Text2dBundle {
+                    text: Text::with_section(
+                        text,
+                        TextStyle {
+                            color: Color::BLACK,
+                            font: asset_library.font("game"),
+                            font_size: 16.,
+                        },
+                        TextAlignment {
+                            vertical: VerticalAlign::Center,
+                            horizontal: HorizontalAlign::Center,
+                        },
+                    ),
+                    transform: Transform::from_xyz(
+                        transform.translation.x,
+                        transform.translation.y + 24.,
+                        0.5,
+                    ),
+                    ..Default::default()
+                })
+                .insert(PreviewLabel)
+                .insert(SceneVisibility(GameState::Board));
+        }
+    }
+    preview.path = path;
+    preview.ends = ends;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(n: u32) -> Vec<Entity> {
+        (0..n).map(Entity::from_raw).collect()
+    }
+
+    fn set(tiles: &[Entity]) -> HashSet<Entity> {
+        tiles.iter().copied().collect()
+    }
+
+    #[test]
+    fn straight_path() {
+        let t = tiles(5);
+        let next: HashMap<_, _> = (0..4).map(|i| (t[i], vec![t[i + 1]])).collect();
+        let (path, ends) = reachable(t[0], 3, &next);
+        assert_eq!(path, set(&[t[1], t[2], t[3]]));
+        assert_eq!(ends, vec![t[3]]);
+    }
+
+    #[test]
+    fn fork_has_two_ends() {
+        let t = tiles(5);
+        let next: HashMap<_, _> = [
+            (t[0], vec![t[1], t[2]]),
+            (t[1], vec![t[3]]),
+            (t[2], vec![t[4]]),
+        ]
+        .into_iter()
+        .collect();
+        let (path, ends) = reachable(t[0], 2, &next);
+        assert_eq!(path, set(&t[1..]));
+        assert_eq!(ends, vec![t[3], t[4]]);
+    }
+
+    #[test]
+    fn rejoin_has_one_end() {
+        let t = tiles(5);
+        let next: HashMap<_, _> = [
+            (t[0], vec![t[1], t[2]]),
+            (t[1], vec![t[3]]),
+            (t[2], vec![t[3]]),
+            (t[3], vec![t[4]]),
+        ]
+        .into_iter()
+        .collect();
+        let (path, ends) = reachable(t[0], 2, &next);
+        assert_eq!(path, set(&[t[1], t[2], t[3]]));
+        assert_eq!(ends, vec![t[3]]);
+    }
+}
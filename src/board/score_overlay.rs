@@ -1,22 +1,36 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 
+const ROW_HEIGHT: f32 = 110.;
+const ITEM_SPACING: f32 = 36.;
+
 #[derive(Component)]
-pub struct ScoreOverlay;
+pub struct ScoreOverlay {
+    player: usize,
+}
 
 #[derive(Component)]
 pub struct ScoreOverlayCoins {
-    mine: bool,
+    player: usize,
 }
 
 #[derive(Component)]
 pub struct ScoreOverlayBg {
-    mine: bool,
+    player: usize,
 }
 
 #[derive(Component)]
 pub struct ScoreOverlayItem {
-    mine: bool,
+    player: usize,
+    slot: usize,
+}
+
+fn slot_right(player: usize) -> bool {
+    player % 2 == 0
+}
+
+fn slot_top(player: usize) -> f32 {
+    (player / 2) as f32 * ROW_HEIGHT
 }
 
 pub struct ScoreOverlayPlugin;
@@ -48,181 +62,156 @@ pub fn init(
                 ..Default::default()
             })
             .with_children(|parent| {
-                parent
-                    .spawn_bundle(ImageBundle {
-                        style: Style {
-                            position_type: PositionType::Absolute,
-                            position: Rect {
-                                top: Val::Px(0.),
-                                left: Val::Px(0.),
-                                ..Default::default()
-                            },
-                            size: Size::new(Val::Px(640.0), Val::Px(480.0)),
+                for player in 0..MAX_PLAYERS {
+                    let right = slot_right(player);
+                    let top = slot_top(player);
+                    let (bg_image, frame_image) = if right {
+                        ("score_overlay_2", "score_overlay_3_right")
+                    } else {
+                        ("score_overlay_1", "score_overlay_3_left")
+                    };
+                    let coins_position = if right {
+                        Rect {
+                            right: Val::Px(150.0),
+                            top: Val::Px(39.0),
+                            ..Default::default()
+                        }
+                    } else {
+                        Rect {
+                            left: Val::Px(150.0),
+                            top: Val::Px(39.0),
                             ..Default::default()
-                        },
-                        image: asset_library.image("score_overlay_1").into(),
-                        color: UiColor(Color::NONE),
-                        ..Default::default()
-                    })
-                    .insert(ScoreOverlayBg { mine: false });
-                parent
-                    .spawn_bundle(ImageBundle {
-                        style: Style {
-                            position_type: PositionType::Absolute,
-                            position: Rect {
-                                top: Val::Px(0.),
-                                left: Val::Px(0.),
+                        }
+                    };
+                    parent
+                        .spawn_bundle(ImageBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                position: Rect {
+                                    top: Val::Px(top),
+                                    left: Val::Px(0.),
+                                    ..Default::default()
+                                },
+                                size: Size::new(Val::Px(640.0), Val::Px(480.0)),
                                 ..Default::default()
                             },
-                            size: Size::new(Val::Px(640.0), Val::Px(480.0)),
+                            image: asset_library.image(bg_image).into(),
+                            color: UiColor(Color::NONE),
                             ..Default::default()
-                        },
-                        color: UiColor(Color::NONE),
-                        image: asset_library.image("score_overlay_2").into(),
-                        ..Default::default()
-                    })
-                    .insert(ScoreOverlayBg { mine: true });
-                parent
-                    .spawn_bundle(ImageBundle {
-                        style: Style {
-                            position_type: PositionType::Absolute,
-                            position: Rect {
-                                top: Val::Px(0.),
-                                left: Val::Px(0.),
+                        })
+                        .insert(ScoreOverlayBg { player });
+                    parent
+                        .spawn_bundle(ImageBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                position: Rect {
+                                    top: Val::Px(top),
+                                    left: Val::Px(0.),
+                                    ..Default::default()
+                                },
+                                size: Size::new(Val::Px(640.0), Val::Px(480.0)),
                                 ..Default::default()
                             },
-                            size: Size::new(Val::Px(640.0), Val::Px(480.0)),
+                            visibility: Visibility { is_visible: false },
+                            image: asset_library.image(frame_image).into(),
                             ..Default::default()
-                        },
-                        visibility: Visibility { is_visible: false },
-                        image: asset_library.image("score_overlay_3").into(),
-                        ..Default::default()
-                    })
-                    .insert(ScoreOverlay)
-                    .with_children(|parent| {
-                        parent
-                            .spawn_bundle(TextBundle {
-                                style: Style {
-                                    align_self: AlignSelf::Center,
-                                    position_type: PositionType::Absolute,
-                                    position: Rect {
-                                        left: Val::Px(150.0),
-                                        top: Val::Px(39.0),
+                        })
+                        .insert(ScoreOverlay { player })
+                        .with_children(|parent| {
+                            parent
+                                .spawn_bundle(TextBundle {
+                                    style: Style {
+                                        align_self: AlignSelf::Center,
+                                        position_type: PositionType::Absolute,
+                                        position: coins_position,
                                         ..Default::default()
                                     },
+                                    visibility: Visibility { is_visible: false },
+                                    text: Text::with_section(
+                                        "",
+                                        TextStyle {
+                                            font: asset_library.font("game"),
+                                            font_size: 48.0,
+                                            color: Color::WHITE,
+                                        },
+                                        TextAlignment {
+                                            horizontal: HorizontalAlign::Center,
+                                            vertical: VerticalAlign::Center,
+                                        },
+                                    ),
                                     ..Default::default()
-                                },
-                                visibility: Visibility { is_visible: false },
-                                text: Text::with_section(
-                                    "",
-                                    TextStyle {
-                                        font: asset_library.font("game"),
-                                        font_size: 48.0,
-                                        color: Color::WHITE,
-                                    },
-                                    TextAlignment {
-                                        horizontal: HorizontalAlign::Center,
-                                        vertical: VerticalAlign::Center,
-                                    },
-                                ),
+                                })
+                                .insert(ScoreOverlayCoins { player });
+                        });
+                    for slot in 0..MAX_ITEMS {
+                        let offset = 210. + slot as f32 * ITEM_SPACING;
+                        let item_position = if right {
+                            Rect {
+                                top: Val::Px(80. + top),
+                                right: Val::Px(offset),
                                 ..Default::default()
-                            })
-                            .insert(ScoreOverlayCoins { mine: false });
+                            }
+                        } else {
+                            Rect {
+                                top: Val::Px(80. + top),
+                                left: Val::Px(offset),
+                                ..Default::default()
+                            }
+                        };
                         parent
-                            .spawn_bundle(TextBundle {
+                            .spawn_bundle(ImageBundle {
                                 style: Style {
-                                    align_self: AlignSelf::Center,
                                     position_type: PositionType::Absolute,
-                                    position: Rect {
-                                        right: Val::Px(150.0),
-                                        top: Val::Px(39.0),
-                                        ..Default::default()
-                                    },
+                                    position: item_position,
+                                    size: Size::new(Val::Px(32.0), Val::Px(32.0)),
                                     ..Default::default()
                                 },
                                 visibility: Visibility { is_visible: false },
-                                text: Text::with_section(
-                                    "",
-                                    TextStyle {
-                                        font: asset_library.font("game"),
-                                        font_size: 48.0,
-                                        color: Color::WHITE,
-                                    },
-                                    TextAlignment {
-                                        horizontal: HorizontalAlign::Center,
-                                        vertical: VerticalAlign::Center,
-                                    },
-                                ),
+                                image: asset_library.image("item_mystery").into(),
                                 ..Default::default()
                             })
-                            .insert(ScoreOverlayCoins { mine: true });
-                    });
-                parent
-                    .spawn_bundle(ImageBundle {
-                        style: Style {
-                            position_type: PositionType::Absolute,
-                            position: Rect {
-                                top: Val::Px(80.),
-                                left: Val::Px(210.),
-                                ..Default::default()
-                            },
-                            size: Size::new(Val::Px(32.0), Val::Px(32.0)),
-                            ..Default::default()
-                        },
-                        visibility: Visibility { is_visible: false },
-                        image: asset_library.image("item_rapier").into(),
-                        ..Default::default()
-                    })
-                    .insert(ScoreOverlayItem { mine: false });
-                parent
-                    .spawn_bundle(ImageBundle {
-                        style: Style {
-                            position_type: PositionType::Absolute,
-                            position: Rect {
-                                top: Val::Px(80.),
-                                right: Val::Px(210.),
-                                ..Default::default()
-                            },
-                            size: Size::new(Val::Px(32.0), Val::Px(32.0)),
-                            ..Default::default()
-                        },
-                        visibility: Visibility { is_visible: false },
-                        image: asset_library.image("item_mystery").into(),
-                        ..Default::default()
-                    })
-                    .insert(ScoreOverlayItem { mine: true });
+                            .insert(ScoreOverlayItem { player, slot });
+                    }
+                }
             });
     }
 }
 
-pub fn update(board: Res<Board>, mut query: Query<&mut Visibility, With<ScoreOverlay>>) {
-    for mut visibility in query.iter_mut() {
-        visibility.is_visible = board.score_overlay;
+pub fn update(
+    board: Res<Board>,
+    players: Res<Players>,
+    mut query: Query<(&mut Visibility, &ScoreOverlay)>,
+) {
+    for (mut visibility, overlay) in query.iter_mut() {
+        visibility.is_visible = board.score_overlay && overlay.player < players.len();
     }
 }
 
 pub fn update_coins(
-    game: Res<Game>,
+    players: Res<Players>,
     board: Res<Board>,
+    mode: Res<GameMode>,
     mut query: Query<(&mut Visibility, &mut Text, &ScoreOverlayCoins)>,
     mut query_bg: Query<(&mut UiColor, &ScoreOverlayBg)>,
 ) {
     for (mut visibility, mut text, coins) in query.iter_mut() {
-        visibility.is_visible = board.score_overlay;
-        if coins.mine {
-            text.sections[0].value = format!("{}", game.my_coins);
-        }
-        if !coins.mine {
-            text.sections[0].value = format!("{}", game.your_coins);
+        visibility.is_visible = board.score_overlay && coins.player < players.len();
+        if coins.player >= players.len() {
+            continue;
         }
+        text.sections[0].value = if mode.stars() {
+            format!(
+                "{} / {} stars",
+                players.coins(coins.player),
+                players.list[coins.player].stars
+            )
+        } else {
+            format!("{}", players.coins(coins.player))
+        };
     }
     for (mut color, bg) in query_bg.iter_mut() {
-        if board.score_overlay {
-            if bg.mine {
-                color.0 = game.my_color;
-            } else {
-                color.0 = game.your_color;
-            }
+        if board.score_overlay && bg.player < players.len() {
+            color.0 = players.color(bg.player);
         } else {
             color.0 = Color::rgba(0., 0., 0., 0.);
         }
@@ -230,7 +219,8 @@ pub fn update_coins(
 }
 
 pub fn update_items(
-    mut board: ResMut<Board>,
+    mut players: ResMut<Players>,
+    board: Res<Board>,
     mut query: Query<(
         &mut Visibility,
         &mut UiImage,
@@ -240,55 +230,55 @@ pub fn update_items(
     )>,
     asset_library: Res<AssetLibrary>,
     input: Res<Input<KeyCode>>,
+    item_effects: Res<ItemEffects>,
 ) {
-    if input.pressed(KeyCode::Key5) {
-        board.my_item_use_interpolate -= 0.025;
-    }
-    if input.pressed(KeyCode::Key6) {
-        board.my_item_use_interpolate += 0.025;
-    }
-    if input.pressed(KeyCode::Key7) {
-        board.your_item_use_interpolate -= 0.025;
-    }
-    if input.pressed(KeyCode::Key8) {
-        board.your_item_use_interpolate += 0.025;
+    let debug_keys = [
+        (KeyCode::Key5, KeyCode::Key6),
+        (KeyCode::Key7, KeyCode::Key8),
+    ];
+    for (player, (down, up)) in players.list.iter_mut().zip(debug_keys.iter()) {
+        if input.pressed(*down) {
+            player.item_use_interpolate -= 0.025;
+        }
+        if input.pressed(*up) {
+            player.item_use_interpolate += 0.025;
+        }
+        player.item_use_interpolate = player.item_use_interpolate.clamp(0., 1.);
     }
-    board.my_item_use_interpolate = board.my_item_use_interpolate.clamp(0., 1.);
-    board.your_item_use_interpolate = board.your_item_use_interpolate.clamp(0., 1.);
     for (mut visibility, mut image, mut style, mut color, item) in query.iter_mut() {
+        let player = if let Some(player) = players.list.get(item.player) {
+            player
+        } else {
+            visibility.is_visible = false;
+            continue;
+        };
         if board.score_overlay {
-            if item.mine {
-                let interp = board.my_item_use_interpolate;
-                let mut move_interp = (interp * 1.25).min(1.);
-                move_interp *= move_interp;
-                let color_interp = board.my_item_use_interpolate.powf(10.);
-                style.position.top = Val::Px(80. + board.my_item_use_interpolate * 120.);
-                style.position.right = Val::Px(210. + move_interp * 55.);
-                style.size = Size::new(
-                    Val::Px(32.0 + 64. * move_interp),
-                    Val::Px(32.0 + 64. * move_interp),
-                );
-                color.0 = Color::rgba(1., 1., 1. - color_interp, 1. - color_interp);
+            let interp = if item.slot == player.item_use_slot {
+                player.item_use_interpolate
+            } else {
+                0.
+            };
+            let mut move_interp = (interp * 1.25).min(1.);
+            move_interp *= move_interp;
+            let color_interp = interp.powf(10.);
+            let offset = 210. + item.slot as f32 * ITEM_SPACING;
+            style.position.top = Val::Px(80. + slot_top(item.player) + interp * 120.);
+            if slot_right(item.player) {
+                style.position.right = Val::Px(offset + move_interp * 55.);
+            } else {
+                style.position.left = Val::Px(offset + move_interp * 55.);
+            }
+            style.size = Size::new(
+                Val::Px(32.0 + 64. * move_interp),
+                Val::Px(32.0 + 64. * move_interp),
+            );
+            color.0 = Color::rgba(1., 1., 1. - color_interp, 1. - color_interp);
+            let held = player.items.get(item.slot).copied().unwrap_or(Item::None);
+            if let Some(effect) = item_effects.get(held) {
+                image.0 = asset_library.image(effect.icon());
                 visibility.is_visible = true;
-                image.0 = asset_library.image("item_mystery");
             } else {
-                let interp = board.your_item_use_interpolate;
-                let mut move_interp = (interp * 1.25).min(1.);
-                move_interp *= move_interp;
-                let color_interp = board.your_item_use_interpolate.powf(10.);
-                style.position.top = Val::Px(80. + board.your_item_use_interpolate * 120.);
-                style.position.left = Val::Px(210. + move_interp * 55.);
-                style.size = Size::new(
-                    Val::Px(32.0 + 64. * move_interp),
-                    Val::Px(32.0 + 64. * move_interp),
-                );
-                color.0 = Color::rgba(1., 1., 1. - color_interp, 1. - color_interp);
-                if matches!(board.your_item, Item::Rapier) {
-                    image.0 = asset_library.image("item_rapier");
-                    visibility.is_visible = true;
-                } else {
-                    visibility.is_visible = false;
-                }
+                visibility.is_visible = false;
             }
         } else {
             visibility.is_visible = false;
@@ -1,19 +1,47 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 use bevy_kira_audio::Audio;
+use rand::seq::index::sample;
 
 pub struct ShopOpen;
 
 #[derive(Component)]
 pub struct Shop;
 
+#[derive(Component)]
+pub struct ShopText;
+
+#[derive(Component)]
+pub struct ShopCard {
+    index: usize,
+}
+
+#[derive(Copy, Clone)]
+pub struct ShopOffer {
+    item: Item,
+    price: u32,
+}
+
+#[derive(Default)]
+pub struct Storefront {
+    offers: Vec<ShopOffer>,
+    cursor: usize,
+    confirming: bool,
+}
+
+const DISCARD_KEYS: [KeyCode; MAX_ITEMS] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3];
+const CARD_COLOR: Color = Color::rgba(1., 1., 1., 0.85);
+const CARD_SELECTED_COLOR: Color = Color::rgba(1., 0.85, 0.4, 0.95);
+
 pub struct ShopPlugin;
 
 impl Plugin for ShopPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<ShopOpen>()
+            .init_resource::<Storefront>()
             .add_system(open.label("shop_open"))
-            .add_system(update.after("shop_open"));
+            .add_system(update.after("shop_open"))
+            .add_system(update_cards.after("shop_open"));
     }
 }
 
@@ -24,11 +52,12 @@ pub fn open(
     mut dialogue: ResMut<Dialogue>,
     asset_library: Res<AssetLibrary>,
     difficulty: Res<Difficulty>,
+    players: Res<Players>,
+    item_effects: Res<ItemEffects>,
+    shop_catalogs: Res<Assets<ShopCatalog>>,
+    mut storefront: ResMut<Storefront>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let rapier_cost = match *difficulty {
-        Difficulty::Normal => 5,
-        Difficulty::Hard => 10,
-    };
     for _ in shop_open.iter() {
         if board.first_shop {
             dialogue.add(DialogueEntry {
@@ -39,6 +68,10 @@ pub fn open(
                 text: "Here you can buy a rapier, which can be used to start a duel!".into(),
                 ..Default::default()
             });
+            dialogue.add(DialogueEntry {
+                text: "Or a crystal ball, to peek at what's coming next!".into(),
+                ..Default::default()
+            });
             dialogue.add(DialogueEntry {
                 text: "During a duel, you can steal coins from your opponent.".into(),
                 ..Default::default()
@@ -56,17 +89,52 @@ pub fn open(
                 ..Default::default()
             });
             board.first_shop = false;
-        } else if !matches!(board.your_item, Item::None) {
+        } else if players.list[board.current].inventory_full() {
             dialogue.add(DialogueEntry {
-                text: "You already have an item!".into(),
+                text: "Your bag is full! Discard an item to make room.".into(),
                 ..Default::default()
             });
         }
+        storefront.offers = vec![];
+        storefront.cursor = 0;
+        storefront.confirming = false;
+        match shop_catalogs.get(asset_library.shop("default")) {
+            Some(catalog) => {
+                if let Err(err) = catalog.validate() {
+                    error!("shop catalog \"default\" is invalid: {}", err);
+                } else {
+                    let entries = catalog.in_stock(&board.shop_sold);
+                    let rng = game_rng.stream(RngStream::Shop);
+                    let mut picks: Vec<usize> =
+                        sample(rng, entries.len(), catalog.offers.min(entries.len())).into_vec();
+                    picks.sort_unstable();
+                    storefront.offers = picks
+                        .into_iter()
+                        .map(|pick| ShopOffer {
+                            item: entries[pick].item,
+                            price: entries[pick].price.get(&difficulty),
+                        })
+                        .collect();
+                }
+            }
+            None => error!("shop catalog \"default\" failed to load"),
+        }
+        let text_style = |font_size: f32| TextStyle {
+            font: asset_library.font("game"),
+            font_size,
+            color: Color::BLACK,
+        };
+        let text_alignment = TextAlignment {
+            horizontal: HorizontalAlign::Center,
+            vertical: VerticalAlign::Center,
+        };
         commands
             .spawn_bundle(NodeBundle {
                 style: Style {
                     size: Size::new(Val::Percent(100.0), Val::Percent(50.0)),
+                    flex_direction: FlexDirection::ColumnReverse,
                     justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
                     position_type: PositionType::Absolute,
                     position: Rect {
                         bottom: Val::Px(0.0),
@@ -78,25 +146,86 @@ pub fn open(
                 ..Default::default()
             })
             .with_children(|parent| {
-                parent.spawn_bundle(TextBundle {
-                    style: Style {
-                        align_self: AlignSelf::Center,
-                        ..Default::default()
-                    },
-                    text: Text::with_section(
-                        format!("SPACE - Leave Shop\nR - Buy Rapier ({} coins)", rapier_cost),
-                        TextStyle {
-                            font: asset_library.font("game"),
-                            font_size: 24.0,
-                            color: Color::BLACK,
+                parent
+                    .spawn_bundle(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            ..Default::default()
                         },
-                        TextAlignment {
-                            horizontal: HorizontalAlign::Center,
-                            vertical: VerticalAlign::Center,
+                        color: Color::NONE.into(),
+                        ..Default::default()
+                    })
+                    .with_children(|parent| {
+                        for (index, offer) in storefront.offers.iter().enumerate() {
+                            let effect = if let Some(effect) = item_effects.get(offer.item) {
+                                effect
+                            } else {
+                                continue;
+                            };
+                            parent
+                                .spawn_bundle(NodeBundle {
+                                    style: Style {
+                                        size: Size::new(Val::Px(150.0), Val::Px(190.0)),
+                                        margin: Rect::all(Val::Px(8.0)),
+                                        flex_direction: FlexDirection::ColumnReverse,
+                                        justify_content: JustifyContent::SpaceAround,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    color: CARD_COLOR.into(),
+                                    ..Default::default()
+                                })
+                                .with_children(|parent| {
+                                    parent.spawn_bundle(ImageBundle {
+                                        style: Style {
+                                            size: Size::new(Val::Px(48.0), Val::Px(48.0)),
+                                            ..Default::default()
+                                        },
+                                        image: asset_library.image(effect.icon()).into(),
+                                        ..Default::default()
+                                    });
+                                    parent.spawn_bundle(TextBundle {
+                                        text: Text::with_section(
+                                            effect.name(),
+                                            text_style(24.0),
+                                            text_alignment,
+                                        ),
+                                        ..Default::default()
+                                    });
+                                    parent.spawn_bundle(TextBundle {
+                                        text: Text::with_section(
+                                            effect.description(),
+                                            text_style(16.0),
+                                            text_alignment,
+                                        ),
+                                        ..Default::default()
+                                    });
+                                    parent.spawn_bundle(TextBundle {
+                                        text: Text::with_section(
+                                            format!("{} coins", offer.price),
+                                            text_style(20.0),
+                                            text_alignment,
+                                        ),
+                                        ..Default::default()
+                                    });
+                                })
+                                .insert(ShopCard { index });
+                        }
+                    });
+                parent
+                    .spawn_bundle(TextBundle {
+                        style: Style {
+                            margin: Rect::all(Val::Px(8.0)),
+                            ..Default::default()
                         },
-                    ),
-                    ..Default::default()
-                });
+                        text: Text::with_section(
+                            shop_text(&storefront, &players, board.current, &item_effects),
+                            text_style(24.0),
+                            text_alignment,
+                        ),
+                        ..Default::default()
+                    })
+                    .insert(ShopText);
             })
             .insert(Shop);
     }
@@ -105,18 +234,20 @@ pub fn open(
 pub fn update(
     mut dialogue: ResMut<Dialogue>,
     mut board: ResMut<Board>,
-    mut game: ResMut<Game>,
+    mut players: CoinLedger,
     mut commands: Commands,
     input: Res<Input<KeyCode>>,
     shop_query: Query<Entity, With<Shop>>,
+    mut text_query: Query<&mut Text, With<ShopText>>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
     difficulty: Res<Difficulty>,
+    item_effects: Res<ItemEffects>,
+    mut storefront: ResMut<Storefront>,
+    game: Res<Game>,
+    mut history: ResMut<History>,
+    ruleset: Res<Ruleset>,
 ) {
-    let rapier_cost = match *difficulty {
-        Difficulty::Normal => 5,
-        Difficulty::Hard => 10,
-    };
     let buy_buffer = match *difficulty {
         Difficulty::Normal => 1,
         Difficulty::Hard => 3,
@@ -128,46 +259,163 @@ pub fn update(
     if dialogue.busy() || !shop_open {
         return;
     }
-    if !(matches!(board.your_item, Item::None)) {
-        board.shop = false;
-        for entity in shop_query.iter() {
-            commands.entity(entity).despawn_recursive();
+    let keys = players.keys(board.current);
+    let offers = storefront.offers.len();
+    let mut changed = false;
+    if storefront.confirming {
+        if input.just_pressed(KeyCode::Return) {
+            storefront.confirming = false;
+            changed = true;
+            let offer = storefront.offers[storefront.cursor];
+            let name = item_effects
+                .get(offer.item)
+                .map(|effect| effect.name().to_lowercase())
+                .unwrap_or_default();
+            let player = &players.list[board.current];
+            let affordable = if ruleset.rigged() {
+                player.coins() > offer.price + buy_buffer
+            } else {
+                player.coins() >= offer.price
+            };
+            if player.inventory_full() {
+                dialogue.add(DialogueEntry {
+                    text: "Your bag is full! Discard an item first.".into(),
+                    ..Default::default()
+                });
+            } else if affordable {
+                audio.play(asset_library.audio("itembuy"));
+                if let Some(mut buyer) = players.player_mut(board.current) {
+                    buyer.give_item(offer.item);
+                }
+                *board.shop_sold.entry(offer.item).or_insert(0) += 1;
+                history.record(
+                    game.turn,
+                    HistoryEvent::Bought {
+                        player: board.current,
+                        item: offer.item,
+                        price: offer.price,
+                    },
+                );
+                players.change(board.current, -(offer.price as i32), CoinReason::Shop);
+                board.shop = false;
+                for entity in shop_query.iter() {
+                    commands.entity(entity).despawn_recursive();
+                }
+                dialogue.add(DialogueEntry {
+                    text: format!("You bought a {}!", name),
+                    ..Default::default()
+                });
+                return;
+            } else if ruleset.rigged() && player.coins() >= offer.price {
+                dialogue.add(DialogueEntry {
+                    text: "Sorry, but you're about to land on a red tile!".into(),
+                    ..Default::default()
+                });
+                dialogue.add(DialogueEntry {
+                    text: "If you buy this now, you will lose the game!".into(),
+                    ..Default::default()
+                });
+            } else {
+                dialogue.add(DialogueEntry {
+                    text: "Sorry! You can't afford it!".into(),
+                    ..Default::default()
+                });
+            }
+        } else if input.just_pressed(KeyCode::Escape) {
+            storefront.confirming = false;
+            changed = true;
         }
-        return;
-    }
-    if input.just_pressed(KeyCode::Space) {
+    } else if input.just_pressed(KeyCode::Space) {
         board.shop = false;
         for entity in shop_query.iter() {
             commands.entity(entity).despawn_recursive();
         }
-    } else if input.just_pressed(KeyCode::R) {
-        if game.your_coins > rapier_cost + buy_buffer {
-            audio.play(asset_library.audio("itembuy"));
-            board.your_item = Item::Rapier;
-            board.your_item_use_interpolate = 0.;
-            board.shop = false;
-            for entity in shop_query.iter() {
-                commands.entity(entity).despawn_recursive();
-            }
-            game.your_coins -= rapier_cost;
-            dialogue.add(DialogueEntry {
-                text: "You bought a rapier!".into(),
-                ..Default::default()
-            });
-        } else if game.your_coins >= rapier_cost {
-            dialogue.add(DialogueEntry {
-                text: "Sorry, but you're about to land on a red tile!".into(),
-                ..Default::default()
-            });
-            dialogue.add(DialogueEntry {
-                text: "If you buy this now, you will lose the game!".into(),
-                ..Default::default()
-            });
-        } else {
+        return;
+    } else if input.just_pressed(KeyCode::Return) && offers > 0 {
+        storefront.confirming = true;
+        changed = true;
+    } else if input.just_pressed(keys.left) && offers > 0 {
+        storefront.cursor = (storefront.cursor + offers - 1) % offers;
+        changed = true;
+    } else if input.just_pressed(keys.right) && offers > 0 {
+        storefront.cursor = (storefront.cursor + 1) % offers;
+        changed = true;
+    } else if let Some(slot) = DISCARD_KEYS
+        .iter()
+        .position(|key| input.just_pressed(*key))
+        .filter(|slot| *slot < players.list[board.current].items.len())
+    {
+        let item = players
+            .player_mut(board.current)
+            .map_or(Item::None, |mut seller| seller.remove_item(slot));
+        history.record(
+            game.turn,
+            HistoryEvent::Discarded {
+                player: board.current,
+                item,
+            },
+        );
+        if let Some(effect) = item_effects.get(item) {
             dialogue.add(DialogueEntry {
-                text: "Sorry! You can't afford it!".into(),
+                text: format!("You threw away the {}.", effect.name().to_lowercase()),
                 ..Default::default()
             });
         }
+        changed = true;
+    }
+    if changed {
+        for mut text in text_query.iter_mut() {
+            text.sections[0].value = shop_text(&storefront, &players, board.current, &item_effects);
+        }
+    }
+}
+
+pub fn update_cards(storefront: Res<Storefront>, mut query: Query<(&mut UiColor, &ShopCard)>) {
+    for (mut color, card) in query.iter_mut() {
+        color.0 = if card.index == storefront.cursor {
+            CARD_SELECTED_COLOR
+        } else {
+            CARD_COLOR
+        };
+    }
+}
+
+pub fn shop_text(
+    storefront: &Storefront,
+    players: &Players,
+    player: usize,
+    item_effects: &ItemEffects,
+) -> String {
+    let offer = storefront.offers.get(storefront.cursor);
+    let name = |item: Item| {
+        item_effects
+            .get(item)
+            .map(|effect| effect.name())
+            .unwrap_or("")
+    };
+    if let (true, Some(offer)) = (storefront.confirming, offer) {
+        return format!(
+            "Buy {} for {} coins?\nENTER - Buy\nESCAPE - Cancel",
+            name(offer.item),
+            offer.price
+        );
+    }
+    let mut text = if offer.is_some() {
+        let keys = players.keys(player);
+        format!(
+            "{}/{} - Browse\nENTER - Select\nSPACE - Leave Shop",
+            key_name(keys.left),
+            key_name(keys.right)
+        )
+    } else {
+        "Sold out!\nSPACE - Leave Shop".to_string()
+    };
+    for (slot, item) in players.list[player].items.iter().enumerate() {
+        text.push_str(&format!(
+            "\n{} - Discard {}",
+            key_name(DISCARD_KEYS[slot]),
+            name(*item)
+        ));
     }
+    text
 }
@@ -0,0 +1,210 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use bevy_kira_audio::Audio;
+use rand::prelude::*;
+
+const STAR_COLOR: Color = Color::rgb(1., 0.85, 0.1);
+
+#[derive(Component)]
+pub struct StarMarker;
+
+#[derive(Component)]
+pub struct StarPrompt;
+
+pub struct StarPlugin;
+
+impl Plugin for StarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(place_star)
+            .add_system(update_marker)
+            .add_system(update);
+    }
+}
+
+pub fn star_price(difficulty: &Difficulty, final_stretch: bool) -> u32 {
+    let price = match *difficulty {
+        Difficulty::Normal => 10,
+        Difficulty::Hard => 20,
+    };
+    if final_stretch {
+        price / 2
+    } else {
+        price
+    }
+}
+
+fn next_star_tile(board: &Board, tile_query: &Query<&Tile>, rng: &mut impl Rng) -> Option<Entity> {
+    let candidates: Vec<Entity> = board
+        .tiles
+        .iter()
+        .copied()
+        .filter(|entity| Some(*entity) != board.star_tile && Some(*entity) != board.start_tile)
+        .filter(|entity| {
+            tile_query.get(*entity).map_or(false, |tile| {
+                matches!(tile.tile_type, TileType::Blue | TileType::Red)
+            })
+        })
+        .collect();
+    candidates.choose(rng).copied()
+}
+
+pub fn place_star(
+    mut board: ResMut<Board>,
+    mode: Res<GameMode>,
+    tile_query: Query<&Tile>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    if !mode.stars() || board.star_tile.is_some() || board.tiles.is_empty() {
+        return;
+    }
+    board.star_tile = next_star_tile(&board, &tile_query, game_rng.stream(RngStream::Board));
+}
+
+pub fn update_marker(
+    mut commands: Commands,
+    board: Res<Board>,
+    time: Res<Clock>,
+    tile_query: Query<&Transform, (With<Tile>, Without<StarMarker>)>,
+    mut marker_query: Query<(Entity, &mut Transform), With<StarMarker>>,
+) {
+    let position = board
+        .star_tile
+        .and_then(|star_tile| tile_query.get(star_tile).ok())
+        .map(|transform| transform.translation.truncate());
+    let mut found = false;
+    for (entity, mut transform) in marker_query.iter_mut() {
+        found = true;
+        if let Some(position) = position {
+            let bob = (time.seconds_since_startup() as f32 * 3.).sin() * 3.;
+            transform.translation.x = position.x;
+            transform.translation.y = position.y + 26. + bob;
+            transform.rotation = Quat::from_rotation_z(std::f32::consts::FRAC_PI_4);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+    if let Some(position) = position.filter(|_| !found) {
+        commands
+            .spawn_bundle(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Vec2::new(14., 14.).into(),
+                    color: STAR_COLOR,
+                    ..Default::default()
+                },
+                transform: Transform::from_xyz(position.x, position.y + 26., 0.6),
+                ..Default::default()
+            })
+            .insert(StarMarker)
+            .insert(SceneVisibility(GameState::Board));
+    }
+}
+
+pub fn update(
+    mut commands: Commands,
+    mut board: ResMut<Board>,
+    mut players: CoinLedger,
+    mut dialogue: ResMut<Dialogue>,
+    mut history: ResMut<History>,
+    mut game_rng: ResMut<GameRng>,
+    input: Res<Input<KeyCode>>,
+    game: Res<Game>,
+    difficulty: Res<Difficulty>,
+    audio: Res<Audio>,
+    asset_library: Res<AssetLibrary>,
+    tile_query: Query<&Tile>,
+    prompt_query: Query<Entity, With<StarPrompt>>,
+) {
+    if !board.star_offer || dialogue.busy() {
+        return;
+    }
+    let player = board.current;
+    let price = star_price(&difficulty, board.final_stretch);
+    let prompt = prompt_query.iter().next();
+    let buy = if players.coins(player) < price {
+        dialogue.add(DialogueEntry {
+            text: format!(
+                "A star costs {} coins. {} can't afford it!",
+                price,
+                players.subject(player)
+            ),
+            ..Default::default()
+        });
+        Some(false)
+    } else if !players.is_human(player) {
+        Some(true)
+    } else if prompt.is_none() {
+        commands
+            .spawn_bundle(NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Percent(50.0)),
+                    justify_content: JustifyContent::Center,
+                    position_type: PositionType::Absolute,
+                    position: Rect {
+                        bottom: Val::Px(0.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                color: Color::NONE.into(),
+                ..Default::default()
+            })
+            .with_children(|parent| {
+                parent.spawn_bundle(TextBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        ..Default::default()
+                    },
+                    text: Text::with_section(
+                        format!("Buy a star for {} coins?\nENTER - Buy\nSPACE - Skip", price),
+                        TextStyle {
+                            font: asset_library.font("game"),
+                            font_size: 24.0,
+                            color: Color::BLACK,
+                        },
+                        TextAlignment {
+                            horizontal: HorizontalAlign::Center,
+                            vertical: VerticalAlign::Center,
+                        },
+                    ),
+                    ..Default::default()
+                });
+            })
+            .insert(StarPrompt);
+        None
+    } else if input.just_pressed(KeyCode::Return) {
+        Some(true)
+    } else if input.just_pressed(KeyCode::Space) {
+        Some(false)
+    } else {
+        None
+    };
+    let buy = if let Some(buy) = buy {
+        buy
+    } else {
+        return;
+    };
+    for entity in prompt_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    board.star_offer = false;
+    if !buy {
+        return;
+    }
+    audio.play(asset_library.audio("itembuy"));
+    history.record(game.turn, HistoryEvent::StarBought { player, price });
+    players.change(player, -(price as i32), CoinReason::Star);
+    if let Some(mut buyer) = players.player_mut(player) {
+        buyer.add_star();
+    }
+    dialogue.add(DialogueEntry {
+        text: format!("{} got a star!", players.subject(player)),
+        ..Default::default()
+    });
+    board.star_tile = next_star_tile(&board, &tile_query, game_rng.stream(RngStream::Board));
+    if board.star_tile.is_some() {
+        dialogue.add(DialogueEntry {
+            text: "The star has moved somewhere else on the board!".into(),
+            ..Default::default()
+        });
+    }
+}
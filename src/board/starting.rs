@@ -28,6 +28,7 @@ pub fn init(
     game: Res<Game>,
     mut board: ResMut<Board>,
     difficulty: Res<Difficulty>,
+    players: Res<Players>,
 ) {
     let follow_entity = commands
         .spawn()
@@ -36,7 +37,7 @@ pub fn init(
         .insert(StartingPan)
         .id();
     starting.time = 0.;
-    camera_controller.zoom_out = true;
+    camera_controller.zoom = CameraController::ZOOM_OUT;
     camera_controller.follow_entity = Some(follow_entity);
     if game.turn == 1 {
         dialogue.add(DialogueEntry {
@@ -86,7 +87,12 @@ pub fn init(
             text: "Let's begin!!".into(),
             ..Default::default()
         });
-    } else if matches!(board.your_item, Item::Rapier) && board.rapier_dialog {
+    } else if players
+        .list
+        .iter()
+        .any(|player| player.controller == Controller::Human && player.has_item(Item::Rapier))
+        && board.rapier_dialog
+    {
         dialogue.add(DialogueEntry {
             text: "Remember: Whoever runs out of coins first loses!".into(),
             ..Default::default()
@@ -109,18 +115,19 @@ pub fn init(
 
 pub fn cleanup(mut starting: ResMut<Starting>, mut camera_controller: ResMut<CameraController>) {
     starting.time = 0.;
-    camera_controller.zoom_out = false;
+    camera_controller.zoom = CameraController::ZOOM_IN;
 }
 
 pub fn update(
-    game: Res<Game>,
+    players: Res<Players>,
+    mode: Res<GameMode>,
     mut starting: ResMut<Starting>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut board_state: ResMut<State<BoardState>>,
     dialogue: Res<Dialogue>,
     mut pan_query: Query<&mut Transform, With<StartingPan>>,
 ) {
-    if game.my_coins == 0 || game.your_coins == 0 {
+    if players.broke(&mode) {
         board_state.set(BoardState::EndGame).unwrap();
         return;
     }
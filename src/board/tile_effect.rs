@@ -0,0 +1,434 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+
+pub struct TileContext<'a> {
+    pub player: usize,
+    pub controller: Controller,
+    pub name: String,
+    pub coins: u32,
+    pub bank: u32,
+    pub final_stretch: bool,
+    pub difficulty: &'a Difficulty,
+    pub rng: &'a mut StdRng,
+}
+
+impl<'a> TileContext<'a> {
+    pub fn new(
+        player: usize,
+        players: &Players,
+        board: &Board,
+        difficulty: &'a Difficulty,
+        rng: &'a mut StdRng,
+    ) -> Self {
+        Self {
+            player,
+            controller: players.list[player].controller,
+            name: players.subject(player),
+            coins: players.coins(player),
+            bank: board.bank,
+            final_stretch: board.final_stretch,
+            difficulty,
+            rng,
+        }
+    }
+
+    pub fn amount(&self, normal: u32, hard: u32) -> u32 {
+        match *self.difficulty {
+            Difficulty::Normal => normal,
+            Difficulty::Hard => hard,
+        }
+    }
+}
+
+pub enum TileAction {
+    Coins(i32),
+    Deposit(u32),
+    Withdraw,
+    Warp,
+    Duel,
+    OpenShop,
+    Say(String),
+}
+
+pub trait TileEffect: Send + Sync {
+    fn land(&self, _context: &mut TileContext) -> Vec<TileAction> {
+        vec![]
+    }
+
+    fn pass(&self, _context: &mut TileContext) -> Vec<TileAction> {
+        vec![]
+    }
+
+    fn preview(&self, _difficulty: &Difficulty, _final_stretch: bool) -> Option<String> {
+        None
+    }
+}
+
+pub struct TileEffects {
+    effects: HashMap<TileType, Box<dyn TileEffect>>,
+}
+
+impl TileEffects {
+    pub fn register(&mut self, tile_type: TileType, effect: impl TileEffect + 'static) {
+        self.effects.insert(tile_type, Box::new(effect));
+    }
+
+    pub fn land(&self, tile_type: TileType, context: &mut TileContext) -> Vec<TileAction> {
+        if let Some(effect) = self.effects.get(&tile_type) {
+            effect.land(context)
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn pass(&self, tile_type: TileType, context: &mut TileContext) -> Vec<TileAction> {
+        if let Some(effect) = self.effects.get(&tile_type) {
+            effect.pass(context)
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn preview(
+        &self,
+        tile_type: TileType,
+        difficulty: &Difficulty,
+        final_stretch: bool,
+    ) -> Option<String> {
+        self.effects
+            .get(&tile_type)
+            .and_then(|effect| effect.preview(difficulty, final_stretch))
+    }
+}
+
+impl Default for TileEffects {
+    fn default() -> Self {
+        let mut tile_effects = Self {
+            effects: HashMap::new(),
+        };
+        tile_effects.register(TileType::Blue, CoinEffect { normal: 1, hard: 3 });
+        tile_effects.register(
+            TileType::Red,
+            CoinEffect {
+                normal: -1,
+                hard: -3,
+            },
+        );
+        tile_effects.register(TileType::Green, ShopEffect);
+        tile_effects.register(TileType::Event, EventEffect);
+        tile_effects.register(TileType::Warp, WarpEffect);
+        tile_effects.register(TileType::Bank, BankEffect { normal: 1, hard: 3 });
+        tile_effects.register(TileType::Duel, DuelEffect);
+        tile_effects
+    }
+}
+
+pub struct CoinEffect {
+    pub normal: i32,
+    pub hard: i32,
+}
+
+impl TileEffect for CoinEffect {
+    fn land(&self, context: &mut TileContext) -> Vec<TileAction> {
+        let amount = match *context.difficulty {
+            Difficulty::Normal => self.normal,
+            Difficulty::Hard => self.hard,
+        };
+        if context.final_stretch {
+            vec![TileAction::Coins(amount * 2)]
+        } else {
+            vec![TileAction::Coins(amount)]
+        }
+    }
+
+    fn preview(&self, difficulty: &Difficulty, final_stretch: bool) -> Option<String> {
+        let amount = match *difficulty {
+            Difficulty::Normal => self.normal,
+            Difficulty::Hard => self.hard,
+        };
+        let amount = if final_stretch { amount * 2 } else { amount };
+        let unit = if amount.abs() == 1 { "coin" } else { "coins" };
+        Some(format!("{:+} {}", amount, unit))
+    }
+}
+
+pub struct ShopEffect;
+
+impl TileEffect for ShopEffect {
+    fn land(&self, context: &mut TileContext) -> Vec<TileAction> {
+        self.pass(context)
+    }
+
+    fn pass(&self, context: &mut TileContext) -> Vec<TileAction> {
+        if context.controller == Controller::Human {
+            vec![TileAction::OpenShop]
+        } else {
+            vec![]
+        }
+    }
+
+    fn preview(&self, _difficulty: &Difficulty, _final_stretch: bool) -> Option<String> {
+        Some("Shop".into())
+    }
+}
+
+pub struct EventEffect;
+
+impl TileEffect for EventEffect {
+    fn land(&self, context: &mut TileContext) -> Vec<TileAction> {
+        let (normal, hard) = (context.rng.gen_range(1..=3), context.rng.gen_range(3..=6));
+        let who = &context.name;
+        match context.rng.gen_range(0..3) {
+            0 => {
+                let amount = context.amount(normal, hard);
+                vec![
+                    TileAction::Say(format!("{} found {} coins on the ground!", who, amount)),
+                    TileAction::Coins(amount as i32),
+                ]
+            }
+            1 => {
+                let amount = context.amount(2, 4);
+                vec![
+                    TileAction::Say(format!("A thief! {} lost {} coins!", who, amount)),
+                    TileAction::Coins(-(amount as i32)),
+                ]
+            }
+            _ => vec![
+                TileAction::Say(
+                    "A rapier fell from the sky! The mini game will now be a duel.".into(),
+                ),
+                TileAction::Duel,
+            ],
+        }
+    }
+
+    fn preview(&self, _difficulty: &Difficulty, _final_stretch: bool) -> Option<String> {
+        Some("Event".into())
+    }
+}
+
+pub struct WarpEffect;
+
+impl TileEffect for WarpEffect {
+    fn land(&self, _context: &mut TileContext) -> Vec<TileAction> {
+        vec![TileAction::Warp]
+    }
+
+    fn preview(&self, _difficulty: &Difficulty, _final_stretch: bool) -> Option<String> {
+        Some("Warp".into())
+    }
+}
+
+pub struct BankEffect {
+    pub normal: u32,
+    pub hard: u32,
+}
+
+impl TileEffect for BankEffect {
+    fn land(&self, context: &mut TileContext) -> Vec<TileAction> {
+        if context.bank == 0 {
+            vec![TileAction::Say("The bank is empty...".into())]
+        } else {
+            let who = &context.name;
+            vec![
+                TileAction::Say(format!(
+                    "{} collected {} coins from the bank!",
+                    who, context.bank
+                )),
+                TileAction::Withdraw,
+            ]
+        }
+    }
+
+    fn pass(&self, context: &mut TileContext) -> Vec<TileAction> {
+        let fee = context.amount(self.normal, self.hard).min(context.coins);
+        if fee == 0 {
+            vec![]
+        } else {
+            vec![TileAction::Deposit(fee)]
+        }
+    }
+
+    fn preview(&self, _difficulty: &Difficulty, _final_stretch: bool) -> Option<String> {
+        Some("Bank".into())
+    }
+}
+
+pub struct DuelEffect;
+
+impl TileEffect for DuelEffect {
+    fn land(&self, _context: &mut TileContext) -> Vec<TileAction> {
+        vec![
+            TileAction::Say("A duel tile! The mini game will now be a duel.".into()),
+            TileAction::Duel,
+        ]
+    }
+
+    fn preview(&self, _difficulty: &Difficulty, _final_stretch: bool) -> Option<String> {
+        Some("Duel".into())
+    }
+}
+
+pub fn apply_tile_actions(
+    actions: Vec<TileAction>,
+    player: usize,
+    game: &mut Game,
+    players: &mut CoinLedger,
+    board: &mut Board,
+    dialogue: &mut Dialogue,
+    shop_open: &mut EventWriter<ShopOpen>,
+) -> bool {
+    let mut warp = false;
+    for action in actions {
+        match action {
+            TileAction::Coins(amount) => {
+                players.change(player, amount, CoinReason::Tile);
+            }
+            TileAction::Deposit(amount) => {
+                let deposited = -players.change(player, -(amount as i32), CoinReason::Bank);
+                board.bank += deposited as u32;
+            }
+            TileAction::Withdraw => {
+                players.change(player, board.bank as i32, CoinReason::Bank);
+                board.bank = 0;
+            }
+            TileAction::Warp => {
+                warp = true;
+            }
+            TileAction::Duel => {
+                game.duel = true;
+                if player != Players::HOST {
+                    game.challenger = player;
+                }
+            }
+            TileAction::OpenShop => {
+                board.shop = true;
+                shop_open.send(ShopOpen);
+            }
+            TileAction::Say(text) => {
+                dialogue.add(DialogueEntry {
+                    text,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    warp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::SystemState;
+
+    fn world(coins: u32) -> World {
+        let mut world = World::new();
+        let mut players = Players::default();
+        players.list = vec![
+            Player::new(Color::WHITE, Controller::Host),
+            Player::new(Color::CYAN, Controller::Human),
+        ];
+        world.insert_resource(players);
+        world.insert_resource(Events::<CoinsChanged>::default());
+        world.insert_resource(Events::<ShopOpen>::default());
+        let mut state: SystemState<CoinLedger> = SystemState::new(&mut world);
+        state.get_mut(&mut world).set(1, coins, CoinReason::Start);
+        world
+    }
+
+    fn apply(
+        world: &mut World,
+        game: &mut Game,
+        board: &mut Board,
+        actions: Vec<TileAction>,
+    ) -> bool {
+        let mut state: SystemState<(CoinLedger, EventWriter<ShopOpen>)> = SystemState::new(world);
+        let (mut players, mut shop_open) = state.get_mut(world);
+        let mut dialogue = Dialogue::default();
+        apply_tile_actions(
+            actions,
+            1,
+            game,
+            &mut players,
+            board,
+            &mut dialogue,
+            &mut shop_open,
+        )
+    }
+
+    fn coins(world: &World) -> u32 {
+        world.get_resource::<Players>().unwrap().coins(1)
+    }
+
+    #[test]
+    fn bank_deposit_and_withdraw() {
+        let mut world = world(5);
+        let mut game = Game::default();
+        let mut board = Board::default();
+        apply(
+            &mut world,
+            &mut game,
+            &mut board,
+            vec![TileAction::Deposit(3)],
+        );
+        assert_eq!((coins(&world), board.bank), (2, 3));
+        apply(
+            &mut world,
+            &mut game,
+            &mut board,
+            vec![TileAction::Deposit(4)],
+        );
+        assert_eq!((coins(&world), board.bank), (0, 5));
+        apply(
+            &mut world,
+            &mut game,
+            &mut board,
+            vec![TileAction::Withdraw],
+        );
+        assert_eq!((coins(&world), board.bank), (5, 0));
+    }
+
+    #[test]
+    fn warp_is_returned() {
+        let mut world = world(5);
+        let mut game = Game::default();
+        let mut board = Board::default();
+        assert!(!apply(
+            &mut world,
+            &mut game,
+            &mut board,
+            vec![TileAction::Coins(2)]
+        ));
+        assert!(apply(
+            &mut world,
+            &mut game,
+            &mut board,
+            vec![TileAction::Coins(-9), TileAction::Warp]
+        ));
+        assert_eq!(coins(&world), 0);
+    }
+
+    #[test]
+    fn duel_and_shop() {
+        let mut world = world(5);
+        let mut game = Game {
+            challenger: 3,
+            ..Default::default()
+        };
+        let mut board = Board::default();
+        apply(
+            &mut world,
+            &mut game,
+            &mut board,
+            vec![TileAction::Duel, TileAction::OpenShop],
+        );
+        assert!(game.duel);
+        assert_eq!(game.challenger, 1);
+        assert!(board.shop);
+        let events = world.get_resource::<Events<ShopOpen>>().unwrap();
+        assert_eq!(events.get_reader().iter(events).count(), 1);
+    }
+}
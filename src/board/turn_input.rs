@@ -4,25 +4,35 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct LocalEntity;
 
+#[derive(Component)]
+pub struct TurnInputText;
+
+#[derive(Default)]
+pub struct TurnInput {
+    cursor: usize,
+}
+
 pub struct TurnInputPlugin;
 
 impl Plugin for TurnInputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(BoardState::TurnInput).with_system(init))
+        app.init_resource::<TurnInput>()
+            .add_system_set(SystemSet::on_enter(BoardState::TurnInput).with_system(init))
             .add_system_set(SystemSet::on_exit(BoardState::TurnInput).with_system(cleanup))
             .add_system_set(SystemSet::on_update(BoardState::TurnInput).with_system(update));
     }
 }
 
-pub fn init(mut commands: Commands, asset_library: Res<AssetLibrary>, board: Res<Board>) {
-    if !board.my_turn {
-        let item_text = match board.your_item {
-            Item::None => "",
-            Item::Rapier => "\nR - Use Rapier",
-            Item::CrystalBall => "\nC - Use Crystal Ball",
-            Item::TrumpCard => "\nT - Use Trump Card",
-            Item::TestItem => "\nT - Use Test Item",
-        };
+pub fn init(
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    board: Res<Board>,
+    players: Res<Players>,
+    item_effects: Res<ItemEffects>,
+    mut turn_input: ResMut<TurnInput>,
+) {
+    turn_input.cursor = 0;
+    if players.is_human(board.current) {
         commands
             .spawn_bundle(NodeBundle {
                 style: Style {
@@ -39,25 +49,27 @@ pub fn init(mut commands: Commands, asset_library: Res<AssetLibrary>, board: Res
                 ..Default::default()
             })
             .with_children(|parent| {
-                parent.spawn_bundle(TextBundle {
-                    style: Style {
-                        align_self: AlignSelf::Center,
-                        ..Default::default()
-                    },
-                    text: Text::with_section(
-                        format!("SPACE - Roll Dice\nF - Free Cam{}", item_text),
-                        TextStyle {
-                            font: asset_library.font("game"),
-                            font_size: 24.0,
-                            color: Color::BLACK,
-                        },
-                        TextAlignment {
-                            horizontal: HorizontalAlign::Center,
-                            vertical: VerticalAlign::Center,
+                parent
+                    .spawn_bundle(TextBundle {
+                        style: Style {
+                            align_self: AlignSelf::Center,
+                            ..Default::default()
                         },
-                    ),
-                    ..Default::default()
-                });
+                        text: Text::with_section(
+                            prompt_text(&players, board.current, 0, &item_effects),
+                            TextStyle {
+                                font: asset_library.font("game"),
+                                font_size: 24.0,
+                                color: Color::BLACK,
+                            },
+                            TextAlignment {
+                                horizontal: HorizontalAlign::Center,
+                                vertical: VerticalAlign::Center,
+                            },
+                        ),
+                        ..Default::default()
+                    })
+                    .insert(TurnInputText);
             })
             .insert(LocalEntity);
     }
@@ -74,27 +86,87 @@ pub fn update(
     mut input: ResMut<Input<KeyCode>>,
     mut board_state: ResMut<State<BoardState>>,
     dialogue: Res<Dialogue>,
+    mut players: ResMut<Players>,
+    item_effects: Res<ItemEffects>,
+    mut turn_input: ResMut<TurnInput>,
+    mut text_query: Query<&mut Text, With<TurnInputText>>,
 ) {
     if dialogue.busy() {
         return;
     }
-    if board.my_turn {
+    if !players.is_human(board.current) {
         board_state.set(BoardState::Moving).unwrap();
     } else {
+        let keys = players.keys(board.current);
+        let items = players.list[board.current].items.len();
         if input.just_pressed(KeyCode::Space) {
             board_state.set(BoardState::Moving).unwrap();
             input.reset(KeyCode::Space);
         } else if input.just_pressed(KeyCode::F) {
             board_state.set(BoardState::FreeCam).unwrap();
             input.reset(KeyCode::F);
-        } else if input.just_pressed(KeyCode::R) {
-            if matches!(board.your_item, Item::Rapier) {
-                board_state.set(BoardState::UseItem).unwrap();
-            }
-        } else if input.just_pressed(KeyCode::C) {
-            if matches!(board.your_item, Item::CrystalBall) {
-                board_state.set(BoardState::UseItem).unwrap();
+        } else if input.just_pressed(KeyCode::Return) && items > 0 {
+            players.list[board.current].item_use_slot = turn_input.cursor;
+            board_state.set(BoardState::UseItem).unwrap();
+            input.reset(KeyCode::Return);
+        } else if items > 1 {
+            let cursor = if input.just_pressed(keys.left) {
+                (turn_input.cursor + items - 1) % items
+            } else if input.just_pressed(keys.right) {
+                (turn_input.cursor + 1) % items
+            } else {
+                turn_input.cursor
+            };
+            if cursor != turn_input.cursor {
+                turn_input.cursor = cursor;
+                for mut text in text_query.iter_mut() {
+                    text.sections[0].value =
+                        prompt_text(&players, board.current, cursor, &item_effects);
+                }
             }
         }
     }
 }
+
+pub fn prompt_text(
+    players: &Players,
+    player: usize,
+    cursor: usize,
+    item_effects: &ItemEffects,
+) -> String {
+    let mut text = if players.humans() > 1 {
+        format!("{}\n", players.subject(player))
+    } else {
+        String::new()
+    };
+    text.push_str("SPACE - Roll Dice\nF - Free Cam   M - Map   H - History");
+    let items = &players.list[player].items;
+    if let Some(selected) = items.get(cursor).and_then(|item| item_effects.get(*item)) {
+        let keys = players.keys(player);
+        let names: Vec<String> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, item)| {
+                let name = item_effects.get(*item)?.name();
+                Some(if slot == cursor {
+                    format!("[{}]", name)
+                } else {
+                    name.to_string()
+                })
+            })
+            .collect();
+        if items.len() > 1 {
+            text.push_str(&format!(
+                "\n{}/{} - Choose Item",
+                key_name(keys.left),
+                key_name(keys.right)
+            ));
+        }
+        text.push_str(&format!(
+            "\nENTER - {}\n{}",
+            selected.prompt(),
+            names.join("  ")
+        ));
+    }
+    text
+}
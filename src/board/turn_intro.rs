@@ -28,13 +28,35 @@ pub fn init(
     mut board: ResMut<Board>,
     mut camera_controller: ResMut<CameraController>,
     audio: Res<Audio>,
+    players: Res<Players>,
+    game: Res<Game>,
+    mode: Res<GameMode>,
+    mut dialogue: ResMut<Dialogue>,
 ) {
     audio.play(asset_library.audio("turnstart"));
-    if board.my_turn {
-        board.active_pawn = board.my_pawn;
-    } else {
-        board.active_pawn = board.your_pawn;
+    let final_stretch = mode.final_stretch(game.turn);
+    if final_stretch && !board.final_stretch {
+        dialogue.add(DialogueEntry {
+            text: format!("Only {} turns left!", GameMode::FINAL_TURNS),
+            ..Default::default()
+        });
+        dialogue.add(DialogueEntry {
+            text: "From now on, blue and red tiles are worth double, and stars are half price!"
+                .into(),
+            ..Default::default()
+        });
     }
+    board.final_stretch = final_stretch;
+    let title = match mode.turn_limit() {
+        Some(turn_limit) => format!(
+            "{} Turn\nTurn {}/{}",
+            players.possessive(board.current),
+            game.turn,
+            turn_limit
+        ),
+        None => format!("{} Turn", players.possessive(board.current)),
+    };
+    board.active_pawn = players.list[board.current].pawn;
     turn_intro.animation = Some(Animation::time(1.0));
     if let Some(active_pawn) = board.active_pawn {
         camera_controller.follow_entity = Some(active_pawn);
@@ -63,11 +85,7 @@ pub fn init(
                     ..Default::default()
                 },
                 text: Text::with_section(
-                    if board.my_turn {
-                        "My Turn"
-                    } else {
-                        "Your Turn"
-                    },
+                    title,
                     TextStyle {
                         font: asset_library.font("game"),
                         font_size: 42.0,
@@ -97,7 +115,7 @@ pub fn cleanup(
 
 pub fn update(
     mut turn_intro: ResMut<TurnIntro>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut board_state: ResMut<State<BoardState>>,
 ) {
     if let Some(animation) = &mut turn_intro.animation {
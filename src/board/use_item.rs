@@ -5,8 +5,15 @@ use bevy_kira_audio::Audio;
 pub struct UseItem {
     time: f32,
     item: Item,
+    picking: Option<u32>,
 }
 
+#[derive(Component)]
+pub struct UseItemEntity;
+
+#[derive(Component)]
+pub struct PickRollText;
+
 pub struct UseItemPlugin;
 
 impl Plugin for UseItemPlugin {
@@ -14,6 +21,7 @@ impl Plugin for UseItemPlugin {
         app.insert_resource(UseItem {
             time: 0.0,
             item: Item::None,
+            picking: None,
         })
         .add_system_set(SystemSet::on_enter(BoardState::UseItem).with_system(init))
         .add_system_set(SystemSet::on_exit(BoardState::UseItem).with_system(cleanup))
@@ -22,50 +30,162 @@ impl Plugin for UseItemPlugin {
 }
 
 pub fn init(
+    mut commands: Commands,
     mut game: ResMut<Game>,
     mut use_item: ResMut<UseItem>,
     board: Res<Board>,
     mut dialogue: ResMut<Dialogue>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    mut players: ResMut<Players>,
+    item_effects: Res<ItemEffects>,
+    difficulty: Res<Difficulty>,
+    ruleset: Res<Ruleset>,
+    mut game_rng: ResMut<GameRng>,
+    mut history: ResMut<History>,
+    mini_games: Res<MiniGames>,
 ) {
     use_item.time = 0.;
-    use_item.item = board.your_item;
-    if matches!(use_item.item, Item::CrystalBall) {
-        dialogue.add(DialogueEntry {
-            text: "You used the crystal ball!".into(),
-            ..Default::default()
-        });
-    } else if matches!(use_item.item, Item::Rapier) {
+    use_item.item = players.list[board.current].using_item();
+    if let Some(effect) = item_effects.get(use_item.item) {
         audio.play(asset_library.audio("itemuse"));
-        game.duel = true;
-        dialogue.add(DialogueEntry {
-            text: "You used the rapier!".into(),
-            ..Default::default()
-        });
-        dialogue.add(DialogueEntry {
-            text: "The mini game will now be a duel.".into(),
-            ..Default::default()
-        });
+        history.record(
+            game.turn,
+            HistoryEvent::UsedItem {
+                player: board.current,
+                item: use_item.item,
+            },
+        );
+        let mut context = ItemContext::new(
+            board.current,
+            &game,
+            &players,
+            &difficulty,
+            &ruleset,
+            game_rng.stream(RngStream::Dice),
+        );
+        let actions = effect.use_item(&mut context);
+        let pick_roll = apply_item_actions(
+            actions,
+            &mut context,
+            &mut game,
+            &mut players,
+            &mut dialogue,
+            &mini_games,
+            &history,
+        );
+        if pick_roll {
+            use_item.picking = Some(1);
+            commands
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Percent(50.0)),
+                        justify_content: JustifyContent::Center,
+                        position_type: PositionType::Absolute,
+                        position: Rect {
+                            bottom: Val::Px(0.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    color: Color::NONE.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle {
+                            style: Style {
+                                align_self: AlignSelf::Center,
+                                ..Default::default()
+                            },
+                            text: Text::with_section(
+                                pick_roll_text(1, players.keys(board.current)),
+                                TextStyle {
+                                    font: asset_library.font("game"),
+                                    font_size: 24.0,
+                                    color: Color::BLACK,
+                                },
+                                TextAlignment {
+                                    horizontal: HorizontalAlign::Center,
+                                    vertical: VerticalAlign::Center,
+                                },
+                            ),
+                            ..Default::default()
+                        })
+                        .insert(PickRollText);
+                })
+                .insert(UseItemEntity);
+        }
     }
 }
 
-pub fn cleanup(mut board: ResMut<Board>, mut use_item: ResMut<UseItem>) {
-    board.my_item_use_interpolate = 0.;
-    board.your_item = Item::None;
+pub fn pick_roll_text(value: u32, keys: KeyBindings) -> String {
+    format!(
+        "Roll a {}\n{}/{} - Change\nENTER - Confirm",
+        value,
+        key_name(keys.left),
+        key_name(keys.right)
+    )
+}
+
+pub fn cleanup(
+    mut commands: Commands,
+    board: Res<Board>,
+    mut players: ResMut<Players>,
+    mut use_item: ResMut<UseItem>,
+    entity_query: Query<Entity, With<UseItemEntity>>,
+) {
+    for entity in entity_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let player = &mut players.list[board.current];
+    player.item_use_interpolate = 0.;
+    if player.item_use_slot < player.items.len() {
+        player.items.remove(player.item_use_slot);
+    }
+    player.item_use_slot = 0;
     use_item.time = 0.;
     use_item.item = Item::None;
+    use_item.picking = None;
 }
 
 pub fn update(
-    mut board: ResMut<Board>,
+    board: Res<Board>,
+    mut players: ResMut<Players>,
     mut board_state: ResMut<State<BoardState>>,
     dialogue: Res<Dialogue>,
-    time: Res<Time>,
+    time: Res<Clock>,
+    mut input: ResMut<Input<KeyCode>>,
+    mut use_item: ResMut<UseItem>,
+    mut text_query: Query<&mut Text, With<PickRollText>>,
 ) {
-    board.your_item_use_interpolate += time.delta_seconds();
-    board.your_item_use_interpolate = board.your_item_use_interpolate.clamp(0., 1.);
-    if !dialogue.busy() && board.your_item_use_interpolate == 1. {
+    let keys = players.keys(board.current);
+    let player = &mut players.list[board.current];
+    player.item_use_interpolate += time.delta_seconds();
+    player.item_use_interpolate = player.item_use_interpolate.clamp(0., 1.);
+    if dialogue.busy() {
+        return;
+    }
+    if let Some(value) = use_item.picking {
+        let max = Die::D6.max();
+        let picked = if input.just_pressed(keys.left) {
+            Some(if value > 1 { value - 1 } else { max })
+        } else if input.just_pressed(keys.right) {
+            Some(if value < max { value + 1 } else { 1 })
+        } else {
+            None
+        };
+        if let Some(picked) = picked {
+            use_item.picking = Some(picked);
+            for mut text in text_query.iter_mut() {
+                text.sections[0].value = pick_roll_text(picked, keys);
+            }
+        } else if input.just_pressed(KeyCode::Return) {
+            input.reset(KeyCode::Return);
+            player.next_rolls = Some(vec![value]);
+            use_item.picking = None;
+        }
+    } else if player.item_use_interpolate == 1. {
         board_state.set(BoardState::TurnInput).unwrap();
     }
 }
@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use bevy::prelude::*;
 use bevy_kira_audio::AudioSource;
 use std::collections::HashMap;
@@ -12,6 +13,8 @@ pub struct AssetLibrary {
     texture_atlases: HashMap<String, Handle<TextureAtlas>>,
     audio: HashMap<String, Handle<AudioSource>>,
     fonts: HashMap<String, Handle<Font>>,
+    boards: HashMap<String, Handle<BoardLayout>>,
+    shops: HashMap<String, Handle<ShopCatalog>>,
 }
 
 impl AssetLibrary {
@@ -30,6 +33,18 @@ impl AssetLibrary {
     pub fn font(&self, name: &str) -> Handle<Font> {
         self.fonts.get(name).unwrap().clone()
     }
+
+    pub fn board(&self, name: &str) -> Handle<BoardLayout> {
+        self.boards.get(name).unwrap().clone()
+    }
+
+    pub fn shop(&self, name: &str) -> Handle<ShopCatalog> {
+        self.shops.get(name).unwrap().clone()
+    }
+
+    pub fn has_image(&self, name: &str) -> bool {
+        self.images.contains_key(name)
+    }
 }
 
 pub struct AssetLibraryPlugin;
@@ -53,10 +68,15 @@ pub fn init_assets(
         ("tile_blue", "sprites/tile_blue.png"),
         ("tile_red", "sprites/tile_red.png"),
         ("tile_green", "sprites/tile_green.png"),
+        ("tile_event", "sprites/tile_event.png"),
+        ("tile_warp", "sprites/tile_warp.png"),
+        ("tile_bank", "sprites/tile_bank.png"),
+        ("tile_duel", "sprites/tile_duel.png"),
         ("bg", "sprites/bg.png"),
         ("score_overlay_1", "sprites/score_overlay_1.png"),
         ("score_overlay_2", "sprites/score_overlay_2.png"),
-        ("score_overlay_3", "sprites/score_overlay_3.png"),
+        ("score_overlay_3_left", "sprites/score_overlay_3_left.png"),
+        ("score_overlay_3_right", "sprites/score_overlay_3_right.png"),
         ("item_rapier", "sprites/item_rapier.png"),
         ("item_mystery", "sprites/item_mystery.png"),
         ("boats_bg", "sprites/boats_bg.png"),
@@ -116,6 +136,8 @@ pub fn init_assets(
         ("m_endgame", "sfx/music_4.ogg"),
     ];
     let fonts: Vec<(&str, &str)> = vec![("game", "fonts/Pixellari.ttf")];
+    let boards: Vec<(&str, &str)> = vec![("default", "boards/default.board.ron")];
+    let shops: Vec<(&str, &str)> = vec![("default", "shops/default.shop.ron")];
 
     for image_def in images.iter() {
         asset_library
@@ -146,6 +168,16 @@ pub fn init_assets(
             .fonts
             .insert(font_def.0.into(), asset_server.load(font_def.1));
     }
+    for board_def in boards.iter() {
+        asset_library
+            .boards
+            .insert(board_def.0.into(), asset_server.load(board_def.1));
+    }
+    for shop_def in shops.iter() {
+        asset_library
+            .shops
+            .insert(shop_def.0.into(), asset_server.load(shop_def.1));
+    }
 }
 
 pub fn load(
@@ -1,70 +1,154 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 
+const TRAUMA_DECAY: f32 = 1.5;
+const MAX_SHAKE: f32 = 12.;
+
+#[derive(Copy, Clone, Debug)]
+pub struct CameraBounds {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+pub struct CameraShake {
+    pub trauma: f32,
+}
+
 pub struct CameraController {
     pub center: bool,
-    pub zoom_out: bool,
     pub follow_entity: Option<Entity>,
+    pub focus: Vec2,
+    pub zoom: f32,
+    pub damping: f32,
+    pub zoom_damping: f32,
+    pub bounds: Option<CameraBounds>,
+    position: Vec2,
+    scale: f32,
+    trauma: f32,
+}
+
+impl CameraController {
+    pub const ZOOM_IN: f32 = 0.5;
+    pub const ZOOM_OUT: f32 = 1.;
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn snap(&mut self) {
+        self.position = self.focus;
+        self.scale = self.zoom;
+    }
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self {
+            center: false,
+            follow_entity: None,
+            focus: Vec2::ZERO,
+            zoom: CameraController::ZOOM_IN,
+            damping: 8.,
+            zoom_damping: 6.,
+            bounds: None,
+            position: Vec2::ZERO,
+            scale: CameraController::ZOOM_IN,
+            trauma: 0.,
+        }
+    }
 }
 
 pub struct CameraControllerPlugin;
 
 impl Plugin for CameraControllerPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(CameraController {
-            center: false,
-            zoom_out: false,
-            follow_entity: None,
-        })
-        .add_system_to_stage(CoreStage::PostUpdate, update_camera.label("update_camera"));
+        app.init_resource::<CameraController>()
+            .add_event::<CameraShake>()
+            .add_system(reset)
+            .add_system_to_stage(CoreStage::PostUpdate, update_camera.label("update_camera"));
     }
 }
 
+fn clamp_to_bounds(position: Vec2, bounds: Option<CameraBounds>, half_view: Vec2) -> Vec2 {
+    let bounds = if let Some(bounds) = bounds {
+        bounds
+    } else {
+        return position;
+    };
+    let clamp_axis = |value: f32, min: f32, max: f32, half: f32| {
+        if max - min <= half * 2. {
+            (min + max) / 2.
+        } else {
+            value.clamp(min + half, max - half)
+        }
+    };
+    Vec2::new(
+        clamp_axis(position.x, bounds.min.x, bounds.max.x, half_view.x),
+        clamp_axis(position.y, bounds.min.y, bounds.max.y, half_view.y),
+    )
+}
+
 pub fn update_camera(
     mut camera_controller: ResMut<CameraController>,
+    mut camera_shake: EventReader<CameraShake>,
     mut queries: QuerySet<(
         QueryState<&Transform>,
         QueryState<&mut Transform, With<GameCamera>>,
     )>,
+    windows: Res<Windows>,
+    time: Res<Clock>,
 ) {
-    if camera_controller.center {
-        for mut camera_transform in queries.q1().iter_mut() {
-            camera_transform.translation.x = 0.;
-            camera_transform.translation.y = 0.;
+    let delta = time.delta_seconds();
+    for event in camera_shake.iter() {
+        camera_controller.trauma = (camera_controller.trauma + event.trauma).clamp(0., 1.);
+    }
+    if let Some(follow_entity) = camera_controller.follow_entity {
+        if let Ok(transform) = queries.q0().get(follow_entity) {
+            camera_controller.focus = transform.translation.truncate();
         }
+    }
+    if camera_controller.center {
+        camera_controller.follow_entity = None;
+        camera_controller.focus = Vec2::ZERO;
+        camera_controller.snap();
         camera_controller.center = false;
-    } else {
-        let target_position = if let Some(follow_entity) = camera_controller.follow_entity {
-            if let Ok(transform) = queries.q0().get(follow_entity) {
-                Some(transform.translation.truncate())
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-        if let Some(target_position) = target_position {
-            for mut camera_transform in queries.q1().iter_mut() {
-                camera_transform.translation.x = target_position.x;
-                camera_transform.translation.y = target_position.y;
-            }
-        }
     }
+    let window_size = windows
+        .get_primary()
+        .map(|window| Vec2::new(window.width(), window.height()))
+        .unwrap_or(Vec2::ZERO);
+    let zoom_blend = 1. - (-camera_controller.zoom_damping * delta).exp();
+    camera_controller.scale += (camera_controller.zoom - camera_controller.scale) * zoom_blend;
+    let half_view = window_size * camera_controller.scale / 2.;
+    let bounds = camera_controller.bounds;
+    camera_controller.focus = clamp_to_bounds(camera_controller.focus, bounds, half_view);
+    let follow_blend = 1. - (-camera_controller.damping * delta).exp();
+    let position = camera_controller.position
+        + (camera_controller.focus - camera_controller.position) * follow_blend;
+    camera_controller.position = clamp_to_bounds(position, bounds, half_view);
+    camera_controller.trauma = (camera_controller.trauma - TRAUMA_DECAY * delta).max(0.);
+    let shake = camera_controller.trauma * camera_controller.trauma;
+    let elapsed = time.seconds_since_startup() as f32;
+    let offset = Vec2::new(
+        (elapsed * 53.).sin() + (elapsed * 31.).sin() * 0.5,
+        (elapsed * 47.).cos() + (elapsed * 37.).cos() * 0.5,
+    ) * MAX_SHAKE
+        * shake;
     for mut camera_transform in queries.q1().iter_mut() {
-        if camera_controller.zoom_out {
-            camera_transform.scale.x = 1.;
-            camera_transform.scale.y = 1.;
-        } else {
-            camera_transform.scale.x = 0.5;
-            camera_transform.scale.y = 0.5;
-        }
+        let position = camera_controller.position + offset;
+        camera_transform.translation.x = position.x;
+        camera_transform.translation.y = position.y;
+        camera_transform.scale.x = camera_controller.scale;
+        camera_transform.scale.y = camera_controller.scale;
     }
 }
 
 pub fn reset(mut reset: EventReader<GameReset>, mut camera_controller: ResMut<CameraController>) {
     for _ in reset.iter() {
         camera_controller.center = true;
-        camera_controller.zoom_out = false;
+        camera_controller.zoom = CameraController::ZOOM_IN;
         camera_controller.follow_entity = None;
+        camera_controller.bounds = None;
+        camera_controller.trauma = 0.;
     }
 }
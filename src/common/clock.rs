@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+#[derive(Default)]
+pub struct Clock {
+    delta: f32,
+    elapsed: f64,
+}
+
+impl Clock {
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta
+    }
+
+    pub fn seconds_since_startup(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.delta = delta;
+        self.elapsed += delta as f64;
+    }
+
+    pub fn override_delta(&mut self, delta: f32) {
+        self.elapsed += (delta - self.delta) as f64;
+        self.delta = delta;
+    }
+}
+
+pub struct ClockPlugin;
+
+impl Plugin for ClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Clock::default())
+            .add_system_to_stage(CoreStage::First, update.label("clock"));
+    }
+}
+
+pub fn update(time: Res<Time>, mut clock: ResMut<Clock>) {
+    clock.tick(time.delta_seconds());
+}
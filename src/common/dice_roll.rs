@@ -1,18 +1,95 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 use bevy_kira_audio::{Audio, AudioChannel};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_DICE: usize = 3;
+const MAX_FACE: u32 = 10;
+const D6_FACES: [u32; 6] = [1, 2, 3, 4, 5, 6];
+const D10_FACES: [u32; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+const DIE_SPACING: f32 = 52.;
+const CYCLE_TIME: f32 = 1. / 15.;
+const AUTO_STOP_TIME: f32 = 0.75;
+const STOP_STAGGER: f32 = 0.3;
+const SHOW_TIME: f32 = 0.75;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Die {
+    D6,
+    D10,
+    Custom(Vec<u32>),
+}
+
+impl Die {
+    pub fn faces(&self) -> &[u32] {
+        match self {
+            Die::D6 => &D6_FACES,
+            Die::D10 => &D10_FACES,
+            Die::Custom(faces) => faces,
+        }
+    }
+
+    pub fn max(&self) -> u32 {
+        self.faces().iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn roll(&self, rng: &mut impl Rng) -> u32 {
+        self.faces().choose(rng).copied().unwrap_or(0)
+    }
+}
+
+struct DieState {
+    faces: Vec<u32>,
+    forced: Option<u32>,
+    max: u32,
+    shown: u32,
+    value: Option<u32>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DicePhase {
+    Hidden,
+    Cycling,
+    Settling,
+    Shown,
+}
 
 pub struct DiceState {
-    visible: bool,
+    phase: DicePhase,
     time: f32,
-    value: u32,
+    cycle_time: f32,
+    interactive: bool,
+    dice: Vec<DieState>,
+}
+
+impl DiceState {
+    pub fn waiting_for_press(&self) -> bool {
+        self.phase == DicePhase::Cycling && self.interactive
+    }
 }
 
 pub struct DiceRollStart {
-    pub value: u32,
+    pub dice: Vec<Die>,
+    pub overrides: Vec<Option<u32>>,
+    pub interactive: bool,
 }
 
-pub struct DiceRollEnd;
+impl DiceRollStart {
+    pub fn scripted(die: Die, value: u32) -> Self {
+        Self {
+            dice: vec![die],
+            overrides: vec![Some(value)],
+            interactive: false,
+        }
+    }
+}
+
+pub struct DiceRollResult {
+    pub values: Vec<u32>,
+    pub total: u32,
+}
 
 pub struct DiceRollValue {
     pub value: u32,
@@ -21,23 +98,31 @@ pub struct DiceRollValue {
 pub struct DiceRollHide;
 
 #[derive(Component)]
-pub struct Dice;
+pub struct Dice {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct DiceHint;
 
 pub struct DiceRollPlugin;
 
 impl Plugin for DiceRollPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(DiceState {
-            visible: false,
+            phase: DicePhase::Hidden,
             time: 0.,
-            value: 1,
+            cycle_time: 0.,
+            interactive: false,
+            dice: vec![],
         })
         .add_event::<DiceRollStart>()
-        .add_event::<DiceRollEnd>()
+        .add_event::<DiceRollResult>()
         .add_event::<DiceRollValue>()
         .add_event::<DiceRollHide>()
         .add_system(init)
         .add_system(update)
+        .add_system(reset)
         .add_system_to_stage(CoreStage::PostUpdate, follow_camera.after("update_camera"));
     }
 }
@@ -48,27 +133,45 @@ pub fn init(
     mut asset_library_ready: EventReader<AssetLibraryReady>,
 ) {
     for _ in asset_library_ready.iter() {
+        for index in 0..MAX_DICE {
+            commands
+                .spawn_bundle(SpriteSheetBundle {
+                    texture_atlas: asset_library.texture_atlas("dice_roll"),
+                    transform: Transform::from_xyz(0.0, 0.0, 0.0),
+                    visibility: Visibility { is_visible: false },
+                    ..Default::default()
+                })
+                .insert(Dice { index });
+        }
         commands
-            .spawn_bundle(SpriteSheetBundle {
-                texture_atlas: asset_library.texture_atlas("dice_roll"),
-                transform: Transform::from_xyz(0.0, 0.0, 0.0),
+            .spawn_bundle(Text2dBundle {
+                text: Text::with_section(
+                    "SPACE - Stop",
+                    TextStyle {
+                        color: Color::BLACK,
+                        font: asset_library.font("game"),
+                        font_size: 16.,
+                    },
+                    TextAlignment {
+                        vertical: VerticalAlign::Center,
+                        horizontal: HorizontalAlign::Center,
+                    },
+                ),
                 visibility: Visibility { is_visible: false },
                 ..Default::default()
             })
-            .insert(Dice);
+            .insert(DiceHint);
     }
 }
 
 pub struct AudioState {
     roll: AudioChannel,
-    rolling: bool,
 }
 
 impl Default for AudioState {
     fn default() -> Self {
         Self {
             roll: AudioChannel::new("roll".into()),
-            rolling: false,
         }
     }
 }
@@ -77,62 +180,131 @@ pub fn update(
     mut game: ResMut<Game>,
     mut dice: ResMut<DiceState>,
     mut dice_start: EventReader<DiceRollStart>,
-    mut dice_end: EventWriter<DiceRollEnd>,
+    mut dice_result: EventWriter<DiceRollResult>,
     mut dice_hide: EventReader<DiceRollHide>,
     mut dice_value: EventReader<DiceRollValue>,
-    mut dice_query: Query<(&mut TextureAtlasSprite, &mut Visibility), With<Dice>>,
-    time: Res<Time>,
+    mut dice_query: Query<(&mut TextureAtlasSprite, &mut Visibility, &Dice)>,
+    mut hint_query: Query<&mut Visibility, (With<DiceHint>, Without<Dice>)>,
+    mut input: ResMut<Input<KeyCode>>,
+    dialogue: Res<Dialogue>,
+    time: Res<Clock>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
     mut audio_state: Local<AudioState>,
+    mut game_rng: ResMut<GameRng>,
 ) {
+    let rng = game_rng.stream(RngStream::Dice);
     for event in dice_start.iter() {
         game.dice_roll = true;
-        dice.visible = true;
+        dice.phase = DicePhase::Cycling;
         dice.time = 0.;
-        dice.value = event.value;
+        dice.cycle_time = 0.;
+        dice.interactive = event.interactive;
+        dice.dice = event
+            .dice
+            .iter()
+            .take(MAX_DICE)
+            .enumerate()
+            .map(|(index, die)| {
+                let forced = event.overrides.get(index).copied().flatten();
+                DieState {
+                    faces: die.faces().to_vec(),
+                    forced,
+                    max: die.max().max(forced.unwrap_or(0)),
+                    shown: die.roll(rng),
+                    value: None,
+                }
+            })
+            .collect();
         audio.play_looped_in_channel(asset_library.audio("diceroll"), &audio_state.roll);
-        audio_state.rolling = true;
     }
     for _ in dice_hide.iter() {
-        dice.visible = false;
+        dice.phase = DicePhase::Hidden;
         audio.stop_channel(&audio_state.roll);
     }
     for event in dice_value.iter() {
-        dice.value = event.value;
-    }
-    if dice.visible {
-        if dice.time < 1.5 {
-            dice.time += time.delta_seconds();
-            if dice.time >= 1.5 {
-                dice_end.send(DiceRollEnd);
-                game.dice_roll = false;
-            }
+        let mut remaining = event.value;
+        for die in dice.dice.iter_mut() {
+            die.shown = remaining.min(die.max);
+            remaining -= die.shown;
         }
     }
-    for (mut sprite, mut visibility) in dice_query.iter_mut() {
-        if dice.visible {
-            visibility.is_visible = true;
-            if dice.time >= 0.75 {
-                if audio_state.rolling {
+    dice.time += time.delta_seconds();
+    match dice.phase {
+        DicePhase::Cycling => {
+            dice.cycle_time += time.delta_seconds();
+            if dice.cycle_time >= CYCLE_TIME {
+                dice.cycle_time -= CYCLE_TIME;
+                for die in dice.dice.iter_mut().filter(|die| die.value.is_none()) {
+                    let previous = die.shown;
+                    let next: Vec<u32> = die
+                        .faces
+                        .iter()
+                        .copied()
+                        .filter(|face| *face != previous)
+                        .collect();
+                    die.shown = next.choose(rng).copied().unwrap_or(previous);
+                }
+            }
+            let stopped = dice.dice.iter().filter(|die| die.value.is_some()).count();
+            let stop = if dice.interactive {
+                !dialogue.busy() && input.just_pressed(KeyCode::Space)
+            } else {
+                dice.time >= AUTO_STOP_TIME + stopped as f32 * STOP_STAGGER
+            };
+            if stop {
+                if dice.interactive {
+                    input.reset(KeyCode::Space);
+                }
+                if let Some(die) = dice.dice.get_mut(stopped) {
+                    let value = die.forced.unwrap_or(die.shown);
+                    die.shown = value;
+                    die.value = Some(value);
                     audio.play(asset_library.audio("diceding"));
+                }
+                if dice.dice.iter().all(|die| die.value.is_some()) {
                     audio.stop_channel(&audio_state.roll);
-                    audio_state.rolling = false;
+                    dice.phase = DicePhase::Settling;
+                    dice.time = 0.;
                 }
-                sprite.index = (4 + dice.value) as usize;
-            } else {
-                sprite.index = ((dice.time * 15.) as usize) % 4;
             }
-        } else {
-            visibility.is_visible = false;
         }
+        DicePhase::Settling => {
+            if dice.time >= SHOW_TIME {
+                dice.phase = DicePhase::Shown;
+                game.dice_roll = false;
+                let values: Vec<u32> = dice.dice.iter().filter_map(|die| die.value).collect();
+                dice_result.send(DiceRollResult {
+                    total: values.iter().sum(),
+                    values,
+                });
+            }
+        }
+        DicePhase::Hidden | DicePhase::Shown => {}
+    }
+    let visible = dice.phase != DicePhase::Hidden;
+    for (mut sprite, mut visibility, die_sprite) in dice_query.iter_mut() {
+        match dice.dice.get(die_sprite.index) {
+            Some(die) if visible => {
+                visibility.is_visible = true;
+                sprite.index = (4 + die.shown.min(MAX_FACE)) as usize;
+            }
+            _ => {
+                visibility.is_visible = false;
+            }
+        }
+    }
+    for mut visibility in hint_query.iter_mut() {
+        visibility.is_visible = dice.waiting_for_press() && !dialogue.busy();
     }
 }
 
 pub fn follow_camera(
+    dice: Res<DiceState>,
     mut query: QuerySet<(
         QueryState<&Transform, With<GameCamera>>,
-        QueryState<&mut Transform, With<Dice>>,
+        QueryState<(&mut Transform, &Dice)>,
+        QueryState<&mut Transform, With<DiceHint>>,
     )>,
 ) {
     let mut camera_position = Vec2::new(0., 0.);
@@ -140,14 +312,20 @@ pub fn follow_camera(
         camera_position = transform.translation.truncate();
     }
     camera_position.y += 30.;
-    for mut dice_transform in query.q1().iter_mut() {
-        dice_transform.translation = camera_position.extend(0.9);
+    let center = (dice.dice.len().max(1) - 1) as f32 / 2.;
+    for (mut dice_transform, die) in query.q1().iter_mut() {
+        let offset = (die.index as f32 - center) * DIE_SPACING;
+        dice_transform.translation = (camera_position + Vec2::new(offset, 0.)).extend(0.9);
+    }
+    for mut hint_transform in query.q2().iter_mut() {
+        hint_transform.translation = (camera_position - Vec2::new(0., 36.)).extend(0.9);
     }
 }
 
 pub fn reset(mut dice: ResMut<DiceState>, mut reset_event: EventReader<GameReset>) {
     for _ in reset_event.iter() {
-        dice.visible = false;
+        dice.phase = DicePhase::Hidden;
         dice.time = 0.;
+        dice.dice.clear();
     }
 }
@@ -1,9 +1,12 @@
 use asset_library::AssetLibraryPlugin;
 use bevy::prelude::*;
 use camera_controller::CameraControllerPlugin;
+use clock::ClockPlugin;
 use collision::CollisionPlugin;
 use dialogue::DialoguePlugin;
 use dice_roll::DiceRollPlugin;
+use replay::ReplayPlugin;
+use rng::RngPlugin;
 use scene_visibility::SceneVisibilityPlugin;
 
 pub struct CommonPlugin;
@@ -11,32 +14,42 @@ pub struct CommonPlugin;
 impl Plugin for CommonPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(AssetLibraryPlugin)
+            .add_plugin(ClockPlugin)
             .add_plugin(CollisionPlugin)
             .add_plugin(SceneVisibilityPlugin)
             .add_plugin(CameraControllerPlugin)
             .add_plugin(DialoguePlugin)
-            .add_plugin(DiceRollPlugin);
+            .add_plugin(DiceRollPlugin)
+            .add_plugin(RngPlugin)
+            .add_plugin(ReplayPlugin);
     }
 }
 
 pub mod animation;
+pub mod args;
 pub mod asset_library;
 pub mod camera_controller;
+pub mod clock;
 pub mod collision;
 pub mod collision_shape;
 pub mod dialogue;
 pub mod dice_roll;
+pub mod replay;
+pub mod rng;
 pub mod scene_visibility;
 
 pub mod prelude {
     pub use super::{
         animation::Animation,
         asset_library::{AssetLibrary, AssetLibraryReady},
-        camera_controller::CameraController,
+        camera_controller::{CameraBounds, CameraController, CameraShake},
+        clock::Clock,
         collision::{Collision, CollisionFilter, CollisionQuery},
         collision_shape::CollisionShape,
         dialogue::{Dialogue, DialogueEntry},
-        dice_roll::{DiceRollEnd, DiceRollHide, DiceRollStart, DiceRollValue},
+        dice_roll::{DiceRollHide, DiceRollResult, DiceRollStart, DiceRollValue, Die},
+        replay::Replay,
+        rng::{GameRng, RngStream},
         scene_visibility::SceneVisibility,
     };
 }
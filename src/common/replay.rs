@@ -0,0 +1,264 @@
+use super::args::arg_value;
+use super::rng::seed_from_args;
+use crate::prelude::*;
+use bevy::app::Events;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel};
+use bevy::input::ElementState;
+use bevy::prelude::*;
+use bevy::window::{CursorMoved, WindowId};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+const REPLAY_VERSION: u32 = 2;
+
+#[derive(Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub version: u32,
+    pub seed: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReplayFrame {
+    pub delta: f32,
+    pub keys: Vec<(KeyCode, bool)>,
+    pub buttons: Vec<(MouseButton, bool)>,
+    pub wheel: Vec<(f32, f32, bool)>,
+    pub cursor: Option<(f32, f32)>,
+}
+
+pub enum Replay {
+    Off,
+    Recording {
+        writer: BufWriter<File>,
+        started: bool,
+    },
+    Playback {
+        frames: VecDeque<ReplayFrame>,
+        speed: f32,
+        started: bool,
+    },
+}
+
+impl Replay {
+    pub fn is_playing(&self) -> bool {
+        matches!(self, Replay::Playback { .. })
+    }
+
+    pub fn is_active(&self) -> bool {
+        !matches!(self, Replay::Off)
+    }
+}
+
+pub fn load_replay(path: &str) -> Result<(ReplayHeader, VecDeque<ReplayFrame>), anyhow::Error> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header: ReplayHeader = match lines.next() {
+        Some(line) => ron::from_str(&line?)?,
+        None => anyhow::bail!("replay file is empty"),
+    };
+    if header.version != REPLAY_VERSION {
+        anyhow::bail!(
+            "replay version {} is not supported, expected {}",
+            header.version,
+            REPLAY_VERSION
+        );
+    }
+    let mut frames = VecDeque::new();
+    for line in lines {
+        frames.push_back(ron::from_str(&line?)?);
+    }
+    Ok((header, frames))
+}
+
+pub fn create_recording(path: &str, seed: u64) -> Result<BufWriter<File>, anyhow::Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let header = ReplayHeader {
+        version: REPLAY_VERSION,
+        seed,
+    };
+    writeln!(writer, "{}", ron::to_string(&header)?)?;
+    Ok(writer)
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        let mut replay = Replay::Off;
+        if let Some(path) = arg_value("replay") {
+            match load_replay(&path) {
+                Ok((header, frames)) => {
+                    let speed = arg_value("replay-speed")
+                        .and_then(|speed| speed.parse().ok())
+                        .filter(|speed: &f32| *speed > 0.)
+                        .unwrap_or(1.);
+                    info!("playing replay {} ({} frames)", path, frames.len());
+                    app.insert_resource(GameRng::new(Some(header.seed)));
+                    replay = Replay::Playback {
+                        frames,
+                        speed,
+                        started: false,
+                    };
+                }
+                Err(err) => error!("failed to load replay {}: {}", path, err),
+            }
+        } else if let Some(path) = arg_value("record") {
+            let seed = seed_from_args().unwrap_or_else(rand::random);
+            match create_recording(&path, seed) {
+                Ok(writer) => {
+                    info!("recording replay to {}", path);
+                    app.insert_resource(GameRng::new(Some(seed)));
+                    replay = Replay::Recording {
+                        writer,
+                        started: false,
+                    };
+                }
+                Err(err) => error!("failed to create replay {}: {}", path, err),
+            }
+        }
+        app.insert_resource(replay)
+            .add_startup_system(disable_vsync)
+            .add_system_to_stage(CoreStage::First, record.after("clock"))
+            .add_system_to_stage(CoreStage::First, playback.after("clock"));
+    }
+}
+
+pub fn disable_vsync(replay: Res<Replay>, mut windows: ResMut<Windows>) {
+    if let Replay::Playback { speed, .. } = *replay {
+        if speed > 1. {
+            if let Some(window) = windows.get_primary_mut() {
+                window.set_vsync(false);
+            }
+        }
+    }
+}
+
+pub fn record(
+    mut replay: ResMut<Replay>,
+    game_state: Res<State<GameState>>,
+    clock: Res<Clock>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse_buttons: EventReader<MouseButtonInput>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut cursor_moved: EventReader<CursorMoved>,
+) {
+    let (writer, started) = if let Replay::Recording { writer, started } = replay.as_mut() {
+        (writer, started)
+    } else {
+        return;
+    };
+    if !*started {
+        if matches!(game_state.current(), GameState::Loading) {
+            return;
+        }
+        *started = true;
+    }
+    let frame = ReplayFrame {
+        delta: clock.delta_seconds(),
+        keys: keyboard
+            .iter()
+            .filter_map(|input| {
+                input
+                    .key_code
+                    .map(|key| (key, input.state == ElementState::Pressed))
+            })
+            .collect(),
+        buttons: mouse_buttons
+            .iter()
+            .map(|input| (input.button, input.state == ElementState::Pressed))
+            .collect(),
+        wheel: mouse_wheel
+            .iter()
+            .map(|event| {
+                let pixel = matches!(event.unit, MouseScrollUnit::Pixel);
+                (event.x, event.y, pixel)
+            })
+            .collect(),
+        cursor: cursor_moved
+            .iter()
+            .last()
+            .map(|event| (event.position.x, event.position.y)),
+    };
+    let result = ron::to_string(&frame)
+        .map_err(anyhow::Error::from)
+        .and_then(|line| Ok(writeln!(writer, "{}", line)?))
+        .and_then(|_| Ok(writer.flush()?));
+    if let Err(err) = result {
+        error!("failed to write replay frame, recording stopped: {}", err);
+        *replay = Replay::Off;
+    }
+}
+
+pub fn playback(
+    mut replay: ResMut<Replay>,
+    game_state: Res<State<GameState>>,
+    mut clock: ResMut<Clock>,
+    mut keyboard: ResMut<Events<KeyboardInput>>,
+    mut mouse_buttons: ResMut<Events<MouseButtonInput>>,
+    mut mouse_wheel: ResMut<Events<MouseWheel>>,
+    mut cursor_moved: ResMut<Events<CursorMoved>>,
+) {
+    let (frames, started) = if let Replay::Playback {
+        frames, started, ..
+    } = replay.as_mut()
+    {
+        (frames, started)
+    } else {
+        return;
+    };
+    keyboard.clear();
+    mouse_buttons.clear();
+    mouse_wheel.clear();
+    cursor_moved.clear();
+    if !*started {
+        if matches!(game_state.current(), GameState::Loading) {
+            return;
+        }
+        *started = true;
+    }
+    let frame = if let Some(frame) = frames.pop_front() {
+        frame
+    } else {
+        info!("replay finished");
+        *replay = Replay::Off;
+        return;
+    };
+    clock.override_delta(frame.delta);
+    for (key, pressed) in frame.keys {
+        keyboard.send(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key),
+            state: element_state(pressed),
+        });
+    }
+    for (button, pressed) in frame.buttons {
+        mouse_buttons.send(MouseButtonInput {
+            button,
+            state: element_state(pressed),
+        });
+    }
+    for (x, y, pixel) in frame.wheel {
+        let unit = if pixel {
+            MouseScrollUnit::Pixel
+        } else {
+            MouseScrollUnit::Line
+        };
+        mouse_wheel.send(MouseWheel { unit, x, y });
+    }
+    if let Some((x, y)) = frame.cursor {
+        cursor_moved.send(CursorMoved {
+            id: WindowId::primary(),
+            position: Vec2::new(x, y),
+        });
+    }
+}
+
+fn element_state(pressed: bool) -> ElementState {
+    if pressed {
+        ElementState::Pressed
+    } else {
+        ElementState::Released
+    }
+}
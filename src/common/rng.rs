@@ -0,0 +1,89 @@
+use super::args::arg_value;
+use crate::prelude::*;
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RngStream {
+    Dice,
+    Board,
+    Shop,
+    Boats,
+    Pong,
+    Rps,
+    Duel,
+    EndGame,
+    Noise,
+    MiniGame,
+}
+
+pub struct GameRng {
+    seed: u64,
+    fixed: bool,
+    streams: HashMap<RngStream, StdRng>,
+}
+
+impl GameRng {
+    pub fn new(seed: Option<u64>) -> Self {
+        Self {
+            seed: seed.unwrap_or_else(rand::random),
+            fixed: seed.is_some(),
+            streams: HashMap::new(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.streams.clear();
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut StdRng {
+        let seed = self.seed;
+        self.streams.entry(stream).or_insert_with(|| {
+            StdRng::seed_from_u64(seed ^ (stream as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        })
+    }
+}
+
+pub fn seed_from_args() -> Option<u64> {
+    let value = arg_value("seed")?;
+    match value.parse() {
+        Ok(seed) => Some(seed),
+        Err(_) => {
+            error!("invalid seed \"{}\", using a random seed", value);
+            None
+        }
+    }
+}
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameRng::new(seed_from_args()))
+            .add_startup_system(log_seed)
+            .add_system(reset);
+    }
+}
+
+pub fn log_seed(game_rng: Res<GameRng>) {
+    info!("rng seed: {}", game_rng.seed());
+}
+
+pub fn reset(mut reset: EventReader<GameReset>, mut game_rng: ResMut<GameRng>) {
+    for _ in reset.iter() {
+        let seed = if game_rng.fixed {
+            game_rng.seed
+        } else {
+            rand::random()
+        };
+        game_rng.reseed(seed);
+        info!("rng seed: {}", game_rng.seed());
+    }
+}
@@ -69,12 +69,11 @@ pub fn update(
     input: Res<Input<KeyCode>>,
     mut game_state: ResMut<State<GameState>>,
     mut text_query: Query<&mut Text, With<ContinueText>>,
-    mut reset: EventWriter<GameResetSend>,
 ) {
     if input.just_pressed(KeyCode::Y) {
         game_state.set(GameState::EndGame).unwrap();
     } else if input.just_pressed(KeyCode::N) {
-        reset.send(GameResetSend);
+        game_state.set(GameState::Stats).unwrap();
     }
     for mut text in text_query.iter_mut() {
         text.sections[0].value = "Game Over\n\nContinue?\nY / N".into();
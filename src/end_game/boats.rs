@@ -82,9 +82,10 @@ pub fn update(mut end_game: ResMut<EndGame>) {
 
 pub fn boat_update(
     game: Res<Game>,
+    players: Res<Players>,
     mut boat_query: Query<(Entity, &mut Transform, &Collision, &mut EgBoat, &mut Sprite)>,
     collision_query: Res<CollisionQuery>,
-    timer: Res<Time>,
+    timer: Res<Clock>,
     input: Res<Input<KeyCode>>,
     difficulty: Res<Difficulty>,
 ) {
@@ -93,20 +94,21 @@ pub fn boat_update(
         Difficulty::Hard => 1.5,
     };
     for (_, _, _, _, mut sprite) in boat_query.iter_mut() {
-        sprite.color = game.your_color;
+        sprite.color = players.color(game.challenger);
     }
+    let keys = players.keys(game.challenger);
     for (entity, mut transform, collision, mut boat, mut sprite) in boat_query.iter_mut() {
         boat.movement = Vec2::new(0., 0.);
-        if input.pressed(KeyCode::S) {
+        if input.pressed(keys.down) {
             boat.movement.y -= 1.;
         }
-        if input.pressed(KeyCode::W) {
+        if input.pressed(keys.up) {
             boat.movement.y += 1.;
         }
-        if input.pressed(KeyCode::A) {
+        if input.pressed(keys.left) {
             boat.movement.x -= 1.;
         }
-        if input.pressed(KeyCode::D) {
+        if input.pressed(keys.right) {
             boat.movement.x += 1.;
         }
         boat.movement = boat.movement.normalize_or_zero() * speed;
@@ -154,7 +156,7 @@ pub fn boat_update(
         transform.translation.x = transform.translation.x.min(120.).max(-110.);
         transform.translation.y = transform.translation.y.min(60.).max(-60.);
         boat.invulnerable_timer = (boat.invulnerable_timer - timer.delta_seconds()).max(0.);
-        let mut color = game.your_color;
+        let mut color = players.color(game.challenger);
         if boat.invulnerable_timer > 0. {
             color.set_a(0.2);
         }
@@ -174,12 +176,13 @@ pub struct EgCannonBall {
 pub fn cannon_ball_update(
     mut commands: Commands,
     mut query: Query<(Entity, &mut EgCannonBall, &mut Transform, &mut Sprite)>,
-    time: Res<Time>,
+    time: Res<Clock>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
-    game: Res<Game>,
+    players: Res<Players>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::EndGame);
     for (entity, mut cannon_ball, mut transform, mut sprite) in query.iter_mut() {
         if !cannon_ball.dir_set {
             let angle = Vec2::angle_between(Vec2::new(-1., 0.), transform.translation.truncate())
@@ -196,14 +199,14 @@ pub fn cannon_ball_update(
             cannon_ball.falling_size = cannon_ball.falling_size.min(1.);
             transform.scale.x = cannon_ball.falling_size * 0.4;
             transform.scale.y = cannon_ball.falling_size * 0.4;
-            sprite.color = game.my_color;
+            sprite.color = players.color(Players::HOST);
             sprite.color.set_a(cannon_ball.falling_size * 0.4);
         } else if cannon_ball.landing_size < 0.5 {
             cannon_ball.landing_size += time.delta_seconds() * cannon_ball.speed;
             cannon_ball.landing_size = cannon_ball.landing_size.min(1.);
             transform.scale.x = 1. - cannon_ball.landing_size;
             transform.scale.y = 1. - cannon_ball.landing_size;
-            sprite.color = game.my_color;
+            sprite.color = players.color(Players::HOST);
         } else {
             audio.play(asset_library.audio("waterdrop"));
             commands.entity(entity).despawn();
@@ -217,6 +220,7 @@ pub fn hit_boats(
     mut boat_query: Query<&mut EgBoat>,
     collision_query: Res<CollisionQuery>,
     audio: Res<Audio>,
+    mut camera_shake: EventWriter<CameraShake>,
     asset_library: Res<AssetLibrary>,
 ) {
     for (entity, cannon_ball, transform, collision) in query.iter() {
@@ -234,6 +238,7 @@ pub fn hit_boats(
                             end_game.your_health -= 1;
                         }
                         audio.play(asset_library.audio("boathit"));
+                        camera_shake.send(CameraShake { trauma: 0.5 });
                     }
                 }
             }
@@ -241,9 +246,14 @@ pub fn hit_boats(
     }
 }
 
-pub fn spawn_cannons(mut commands: Commands, asset_library: Res<AssetLibrary>, audio: Res<Audio>) {
+pub fn spawn_cannons(
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    audio: Res<Audio>,
+    mut game_rng: ResMut<GameRng>,
+) {
     let spawn_chance = 0.07;
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::EndGame);
     if rng.gen_bool(spawn_chance) {
         audio.play(asset_library.audio("cannon"));
         let angle = rng.gen_range(0.0..360.0f32).to_radians();
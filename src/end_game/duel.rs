@@ -37,6 +37,7 @@ impl Plugin for EgDuelPlugin {
 pub fn enter(
     mut eg_duel: ResMut<EgDuel>,
     game: Res<Game>,
+    players: Res<Players>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
 ) {
@@ -47,7 +48,7 @@ pub fn enter(
             texture_atlas: asset_library.texture_atlas("duel"),
             transform: Transform::from_xyz(-25., 0., 0.2),
             sprite: TextureAtlasSprite {
-                color: game.your_color,
+                color: players.color(game.challenger),
                 ..Default::default()
             },
             ..Default::default()
@@ -59,7 +60,7 @@ pub fn enter(
             texture: asset_library.image("me_1"),
             transform: Transform::from_xyz(55., 0., 0.),
             sprite: Sprite {
-                color: game.my_color,
+                color: players.color(Players::HOST),
                 ..Default::default()
             },
             ..Default::default()
@@ -71,7 +72,7 @@ pub fn enter(
             texture: asset_library.image("me_2"),
             transform: Transform::from_xyz(55., 0., 0.1),
             sprite: Sprite {
-                color: game.my_color,
+                color: players.color(Players::HOST),
                 ..Default::default()
             },
             ..Default::default()
@@ -91,11 +92,16 @@ pub fn update(
     mut eg_duel: ResMut<EgDuel>,
     input: Res<Input<KeyCode>>,
     mut query: Query<(&mut Transform, &mut TextureAtlasSprite), With<EgDuelist>>,
-    time: Res<Time>,
+    time: Res<Clock>,
     asset_library: Res<AssetLibrary>,
     audio: Res<Audio>,
+    mut camera_shake: EventWriter<CameraShake>,
+    game: Res<Game>,
+    players: Res<Players>,
 ) {
-    if input.pressed(KeyCode::A) || eg_duel.attack_time >= ATTACK_PREP_TIME {
+    if input.pressed(players.keys(game.challenger).attack)
+        || eg_duel.attack_time >= ATTACK_PREP_TIME
+    {
         eg_duel.attack_time += time.delta_seconds();
     }
     let duelist_x = -25.;
@@ -109,6 +115,7 @@ pub fn update(
             if !eg_duel.hit {
                 end_game.my_health -= 1;
                 audio.play(asset_library.audio("duelhit"));
+                camera_shake.send(CameraShake { trauma: 0.5 });
                 eg_duel.hit = true;
             }
             transform.translation.x = duelist_x + 10.;
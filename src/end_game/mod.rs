@@ -58,6 +58,7 @@ impl Plugin for EndGamePlugin {
 
 pub fn enter(
     game: Res<Game>,
+    players: Res<Players>,
     mut end_game_state: ResMut<State<EndGameState>>,
     mut end_game: ResMut<EndGame>,
     mut camera_controller: ResMut<CameraController>,
@@ -78,7 +79,7 @@ pub fn enter(
                 texture: asset_library.image("heart"),
                 transform: Transform::from_xyz(-135. + 40. * (i as f32), 100., 1.),
                 sprite: Sprite {
-                    color: game.your_color,
+                    color: players.color(game.challenger),
                     ..Default::default()
                 },
                 visibility: Visibility { is_visible: false },
@@ -93,7 +94,7 @@ pub fn enter(
                 texture: asset_library.image("heart"),
                 transform: Transform::from_xyz(135. - 40. * (i as f32), 100., 1.),
                 sprite: Sprite {
-                    color: game.my_color,
+                    color: players.color(Players::HOST),
                     ..Default::default()
                 },
                 visibility: Visibility { is_visible: false },
@@ -119,10 +120,9 @@ pub fn exit(
 pub fn update(
     mut end_game_state: ResMut<State<EndGameState>>,
     mut end_game: ResMut<EndGame>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut query: Query<(&mut Handle<Image>, &mut Visibility, &EndGameHeart)>,
     asset_library: Res<AssetLibrary>,
-    mut reset: EventWriter<GameResetSend>,
     mut game_state: ResMut<State<GameState>>,
     difficulty: Res<Difficulty>,
 ) {
@@ -136,7 +136,7 @@ pub fn update(
         } else if end_game.your_health == 0 {
             if end_game.state_time > 2.0 {
                 if matches!(*difficulty, Difficulty::Hard) {
-                    reset.send(GameResetSend);
+                    game_state.set(GameState::Stats).unwrap();
                 } else {
                     game_state.set(GameState::Continue).unwrap();
                 }
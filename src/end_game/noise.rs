@@ -45,8 +45,8 @@ pub fn enter(
         .insert(Noise);
 }
 
-pub fn update(mut noise_query: Query<&mut Transform, With<Noise>>) {
-    let mut rng = rand::thread_rng();
+pub fn update(mut noise_query: Query<&mut Transform, With<Noise>>, mut game_rng: ResMut<GameRng>) {
+    let rng = game_rng.stream(RngStream::Noise);
     for mut transform in noise_query.iter_mut() {
         transform.translation.x = rng.gen_range(-160.0..160.0f32);
         transform.translation.y = rng.gen_range(-120.0..120.0f32);
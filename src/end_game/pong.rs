@@ -33,14 +33,19 @@ impl Plugin for EgPongPlugin {
     }
 }
 
-pub fn enter(mut eg_pong: ResMut<EgPong>, game: Res<Game>, mut commands: Commands) {
+pub fn enter(
+    mut eg_pong: ResMut<EgPong>,
+    game: Res<Game>,
+    players: Res<Players>,
+    mut commands: Commands,
+) {
     eg_pong.next_spawn_time = 0.2;
     eg_pong.lost_health = false;
     commands
         .spawn_bundle(SpriteBundle {
             sprite: Sprite {
                 custom_size: Vec2::new(48., 8.).into(),
-                color: game.your_color,
+                color: players.color(game.challenger),
                 ..Default::default()
             },
             transform: Transform::from_xyz(0., -80., 0.),
@@ -65,8 +70,8 @@ pub fn exit(mut commands: Commands, query: Query<Entity, With<EndGameEntity>>) {
 pub fn update(
     mut eg_pong: ResMut<EgPong>,
     mut commands: Commands,
-    time: Res<Time>,
-    game: Res<Game>,
+    time: Res<Clock>,
+    players: Res<Players>,
     mut end_game: ResMut<EndGame>,
 ) {
     let mut spawn = false;
@@ -82,7 +87,7 @@ pub fn update(
             .spawn_bundle(SpriteBundle {
                 sprite: Sprite {
                     custom_size: Vec2::new(8., 8.).into(),
-                    color: game.my_color,
+                    color: players.color(Players::HOST),
                     ..Default::default()
                 },
                 transform: Transform::from_xyz(0., 100., 0.),
@@ -107,13 +112,16 @@ pub fn update(
 pub fn paddle_update(
     mut paddle_query: Query<&mut Transform, With<EgPaddle>>,
     input: Res<Input<KeyCode>>,
-    time: Res<Time>,
+    time: Res<Clock>,
+    game: Res<Game>,
+    players: Res<Players>,
 ) {
+    let keys = players.keys(game.challenger);
     for mut transform in paddle_query.iter_mut() {
-        if input.pressed(KeyCode::A) {
+        if input.pressed(keys.left) {
             transform.translation.x -= time.delta_seconds() * 250.;
         }
-        if input.pressed(KeyCode::D) {
+        if input.pressed(keys.right) {
             transform.translation.x += time.delta_seconds() * 250.;
         }
         transform.translation.x = transform.translation.x.clamp(-140., 140.);
@@ -131,14 +139,15 @@ impl Default for EgPuck {
 pub fn puck_update(
     mut query: Query<(Entity, &mut Transform, &Collision, &mut EgPuck)>,
     collision_query: Res<CollisionQuery>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut commands: Commands,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
     mut end_game: ResMut<EndGame>,
     mut eg_pong: ResMut<EgPong>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::EndGame);
     for (entity, mut transform, collision, mut puck) in query.iter_mut() {
         let filter = Some(CollisionFilter {
             exclude_entity: entity,
@@ -34,15 +34,17 @@ impl Plugin for EgRpsPlugin {
 pub fn enter(
     mut eg_rps: ResMut<EgRps>,
     game: Res<Game>,
+    players: Res<Players>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
     audio: Res<Audio>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     audio.play(asset_library.audio("shoot"));
     eg_rps.lost_health = false;
-    eg_rps.my_select = RpsSelect::new_rand();
+    eg_rps.my_select = RpsSelect::new_rand(game_rng.stream(RngStream::EndGame));
     eg_rps.your_select = None;
-    let mut color = game.your_color;
+    let mut color = players.color(game.challenger);
     color.set_a(0.3);
     commands
         .spawn_bundle(SpriteSheetBundle {
@@ -62,7 +64,7 @@ pub fn enter(
             texture_atlas: asset_library.texture_atlas("rps"),
             transform: Transform::from_xyz(60., 0., 0.3),
             sprite: TextureAtlasSprite {
-                color: game.my_color,
+                color: players.color(Players::HOST),
                 flip_x: true,
                 index: eg_rps.my_select.to_index(),
                 ..Default::default()
@@ -105,7 +107,10 @@ pub fn update(
     input: ResMut<Input<KeyCode>>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    game: Res<Game>,
+    players: Res<Players>,
 ) {
+    let keys = players.keys(game.challenger);
     for mut sprite in query.iter_mut() {
         if let Some(select) = eg_rps.your_select {
             sprite.color.set_a(1.);
@@ -115,13 +120,13 @@ pub fn update(
         }
     }
     if eg_rps.your_select.is_none() {
-        if input.just_pressed(KeyCode::R) {
+        if input.just_pressed(keys.rock) {
             audio.play(asset_library.audio("shoot"));
             eg_rps.your_select = Some(RpsSelect::Rock);
-        } else if input.just_pressed(KeyCode::P) {
+        } else if input.just_pressed(keys.paper) {
             audio.play(asset_library.audio("shoot"));
             eg_rps.your_select = Some(RpsSelect::Paper);
-        } else if input.just_pressed(KeyCode::S) {
+        } else if input.just_pressed(keys.scissors) {
             audio.play(asset_library.audio("shoot"));
             eg_rps.your_select = Some(RpsSelect::Scissors);
         }
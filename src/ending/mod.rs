@@ -37,9 +37,9 @@ pub fn enter(mut dialogue: ResMut<Dialogue>, difficulty: Res<Difficulty>) {
     }
 }
 
-pub fn update(game: Res<Game>, dialogue: Res<Dialogue>, mut reset: EventWriter<GameResetSend>) {
+pub fn update(game: Res<Game>, dialogue: Res<Dialogue>, mut game_state: ResMut<State<GameState>>) {
     if dialogue.busy() || game.dice_roll {
         return;
     }
-    reset.send(GameResetSend);
+    game_state.set(GameState::Stats).unwrap();
 }
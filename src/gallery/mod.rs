@@ -0,0 +1,192 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use bevy_kira_audio::Audio;
+use std::cmp::Ordering;
+
+#[derive(Component)]
+pub struct GalleryEntity;
+
+#[derive(Component)]
+pub struct GalleryText;
+
+#[derive(Default)]
+pub struct Gallery {
+    selected: usize,
+    versus: bool,
+    hard: bool,
+    played: Option<MiniGameId>,
+}
+
+pub struct GalleryPlugin;
+
+impl Plugin for GalleryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Gallery>()
+            .add_system_set(SystemSet::on_enter(GameState::Gallery).with_system(enter))
+            .add_system_set(SystemSet::on_exit(GameState::Gallery).with_system(exit))
+            .add_system_set(SystemSet::on_update(GameState::Gallery).with_system(update));
+    }
+}
+
+pub fn enter(
+    mut commands: Commands,
+    mut camera_controller: ResMut<CameraController>,
+    asset_library: Res<AssetLibrary>,
+) {
+    camera_controller.center = true;
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            color: Color::BLACK.into(),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn_bundle(TextBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        ..Default::default()
+                    },
+                    text: Text::with_section(
+                        "",
+                        TextStyle {
+                            font: asset_library.font("game"),
+                            font_size: 24.0,
+                            color: Color::WHITE,
+                        },
+                        TextAlignment {
+                            horizontal: HorizontalAlign::Center,
+                            vertical: VerticalAlign::Center,
+                        },
+                    ),
+                    ..Default::default()
+                })
+                .insert(GalleryText);
+        })
+        .insert(GalleryEntity);
+}
+
+pub fn exit(mut commands: Commands, query: Query<Entity, With<GalleryEntity>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn result_text(result: &[(usize, i32)], versus: bool) -> String {
+    let coins = |player| {
+        result
+            .iter()
+            .find(|(index, _)| *index == player)
+            .map_or(0, |(_, coins)| *coins)
+    };
+    let (my_coins, your_coins) = (coins(Players::HOST), coins(1));
+    let host = if versus { "Player 2" } else { "CPU" };
+    let winner = match my_coins.cmp(&your_coins) {
+        Ordering::Greater => format!("{} wins!", host),
+        Ordering::Less => "Player 1 wins!".to_string(),
+        Ordering::Equal => "It's a draw!".to_string(),
+    };
+    format!(
+        "Last result: Player 1 {:+}, {} {:+}\n{}",
+        your_coins, host, my_coins, winner
+    )
+}
+
+pub fn update(
+    mut game: ResMut<Game>,
+    mut gallery: ResMut<Gallery>,
+    mut players: ResMut<Players>,
+    mut difficulty: ResMut<Difficulty>,
+    mut mini_game: ResMut<MiniGame>,
+    mut input: ResMut<Input<KeyCode>>,
+    mut game_state: ResMut<State<GameState>>,
+    mut mini_game_state: ResMut<State<MiniGameState>>,
+    mut text_query: Query<&mut Text, With<GalleryText>>,
+    mini_games: Res<MiniGames>,
+    audio: Res<Audio>,
+    asset_library: Res<AssetLibrary>,
+) {
+    let ids = mini_games.ids();
+    if ids.is_empty() {
+        return;
+    }
+    gallery.selected = gallery.selected.min(ids.len() - 1);
+    if input.just_pressed(KeyCode::Up) {
+        gallery.selected = (gallery.selected + ids.len() - 1) % ids.len();
+    } else if input.just_pressed(KeyCode::Down) {
+        gallery.selected = (gallery.selected + 1) % ids.len();
+    }
+    if input.just_pressed(KeyCode::O) {
+        gallery.versus = !gallery.versus;
+    }
+    if input.just_pressed(KeyCode::D) {
+        gallery.hard = !gallery.hard;
+    }
+    if input.just_pressed(KeyCode::Escape) {
+        gallery.played = None;
+        mini_game.result = None;
+        game_state.set(GameState::Menu).unwrap();
+        input.reset(KeyCode::Escape);
+        return;
+    }
+    if input.just_pressed(KeyCode::Return) {
+        let id = ids[gallery.selected];
+        let host_controller = if gallery.versus {
+            Controller::Human
+        } else {
+            Controller::Host
+        };
+        let mut host = Player::new(Color::rgba(1., 0.7, 0.7, 1.0), host_controller);
+        host.keys = KeyBindings::ARROWS;
+        let mut you = Player::new(Color::CYAN, Controller::Human);
+        you.keys = KeyBindings::WASD;
+        players.list = vec![host, you];
+        game.challenger = 1;
+        *difficulty = if gallery.hard {
+            Difficulty::Hard
+        } else {
+            Difficulty::Normal
+        };
+        gallery.played = Some(id);
+        mini_game.practice = true;
+        mini_game.gallery = true;
+        mini_game.result = None;
+        mini_game_state.set(MiniGameState::Playing(id)).unwrap();
+        game_state.set(GameState::MiniGame).unwrap();
+        audio.play(asset_library.audio("dialogue"));
+        input.reset(KeyCode::Return);
+        return;
+    }
+    let mut lines = vec!["Mini Games".to_string(), String::new()];
+    for (index, id) in ids.iter().enumerate() {
+        let marker = if index == gallery.selected { ">" } else { " " };
+        lines.push(format!("{} {}", marker, mini_games.name(*id)));
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "O - Opponent: {}",
+        if gallery.versus { "Player 2" } else { "CPU" }
+    ));
+    lines.push(format!(
+        "D - Difficulty: {}",
+        if gallery.hard { "Hard" } else { "Normal" }
+    ));
+    if gallery.versus {
+        lines.push("Player 1: WASD   Player 2: ARROWS".into());
+    }
+    if let (Some(played), Some(result)) = (gallery.played, &mini_game.result) {
+        lines.push(String::new());
+        lines.push(mini_games.name(played).to_string());
+        lines.push(result_text(result, gallery.versus));
+    }
+    lines.push(String::new());
+    lines.push("UP/DOWN - Select   ENTER - Play   ESC - Back".into());
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
@@ -0,0 +1,226 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub const HISTORY_TURNS: u32 = 5;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum HistoryEvent {
+    Coins {
+        player: usize,
+        delta: i32,
+        reason: CoinReason,
+    },
+    Rolled {
+        player: usize,
+        total: u32,
+    },
+    Landed {
+        player: usize,
+        tile: TileType,
+    },
+    Bought {
+        player: usize,
+        item: Item,
+        price: u32,
+    },
+    Discarded {
+        player: usize,
+        item: Item,
+    },
+    StarBought {
+        player: usize,
+        price: u32,
+    },
+    UsedItem {
+        player: usize,
+        item: Item,
+    },
+    MiniGame {
+        game: MiniGameId,
+        results: Vec<(usize, i32)>,
+    },
+    Duel {
+        challenger: usize,
+        winner: Option<usize>,
+    },
+}
+
+impl HistoryEvent {
+    pub fn winner(&self) -> Option<usize> {
+        match self {
+            HistoryEvent::MiniGame { results, .. } => {
+                let best = results.iter().map(|(_, coins)| *coins).max()?;
+                let mut winners = results.iter().filter(|(_, coins)| *coins == best);
+                match (winners.next(), winners.next()) {
+                    (Some((player, _)), None) => Some(*player),
+                    _ => None,
+                }
+            }
+            HistoryEvent::Duel { winner, .. } => *winner,
+            _ => None,
+        }
+    }
+
+    pub fn describe(
+        &self,
+        players: &Players,
+        item_effects: &ItemEffects,
+        mini_games: &MiniGames,
+    ) -> String {
+        let item_name = |item: Item| {
+            item_effects
+                .get(item)
+                .map(|effect| effect.name())
+                .unwrap_or("item")
+        };
+        match self {
+            HistoryEvent::Coins {
+                player,
+                delta,
+                reason,
+            } => format!(
+                "{} {:+} coins ({})",
+                players.subject(*player),
+                delta,
+                reason.name()
+            ),
+            HistoryEvent::Rolled { player, total } => {
+                format!("{} rolled {}", players.subject(*player), total)
+            }
+            HistoryEvent::Landed { player, tile } => {
+                format!("{} landed on {}", players.subject(*player), tile.name())
+            }
+            HistoryEvent::Bought {
+                player,
+                item,
+                price,
+            } => format!(
+                "{} bought {} for {}",
+                players.subject(*player),
+                item_name(*item),
+                price
+            ),
+            HistoryEvent::Discarded { player, item } => {
+                format!(
+                    "{} threw away {}",
+                    players.subject(*player),
+                    item_name(*item)
+                )
+            }
+            HistoryEvent::StarBought { player, price } => {
+                format!("{} bought a star for {}", players.subject(*player), price)
+            }
+            HistoryEvent::UsedItem { player, item } => {
+                format!("{} used {}", players.subject(*player), item_name(*item))
+            }
+            HistoryEvent::MiniGame { game, results } => format!(
+                "{}: {}",
+                mini_games.name(*game),
+                results
+                    .iter()
+                    .map(|(player, coins)| format!("{} {:+}", players.subject(*player), coins))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            HistoryEvent::Duel { challenger, winner } => match winner {
+                Some(winner) => format!(
+                    "Duel vs {}: {} won",
+                    players.subject(*challenger),
+                    players.subject(*winner)
+                ),
+                None => format!("Duel vs {}: draw", players.subject(*challenger)),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub turn: u32,
+    pub event: HistoryEvent,
+}
+
+#[derive(Default)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    pub fn record(&mut self, turn: u32, event: HistoryEvent) {
+        self.entries.push(HistoryEntry { turn, event });
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn mini_games(&self) -> Vec<MiniGameId> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry.event {
+                HistoryEvent::MiniGame { game, .. } => Some(game),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn recent(&self, turn: u32) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.turn + HISTORY_TURNS > turn)
+    }
+}
+
+pub struct HistoryPlugin;
+
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<History>()
+            .add_system(reset)
+            .add_system(record_coins);
+    }
+}
+
+pub fn record_coins(
+    game: Res<Game>,
+    mut history: ResMut<History>,
+    mut coins_changed: EventReader<CoinsChanged>,
+) {
+    for event in coins_changed.iter() {
+        history.record(
+            game.turn,
+            HistoryEvent::Coins {
+                player: event.player,
+                delta: event.delta,
+                reason: event.reason,
+            },
+        );
+    }
+}
+
+pub fn reset(mut reset: EventReader<GameReset>, mut history: ResMut<History>) {
+    for _ in reset.iter() {
+        *history = History::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mini_game(results: Vec<(usize, i32)>) -> HistoryEvent {
+        HistoryEvent::MiniGame {
+            game: MiniGameId("test"),
+            results,
+        }
+    }
+
+    #[test]
+    fn mini_game_winner() {
+        assert_eq!(mini_game(vec![(0, 2), (1, 4)]).winner(), Some(1));
+        assert_eq!(mini_game(vec![(0, 4), (2, 1)]).winner(), Some(0));
+        assert_eq!(mini_game(vec![(0, 3), (1, 3)]).winner(), None);
+        assert_eq!(mini_game(vec![(0, 1), (1, 5), (2, 5)]).winner(), None);
+        assert_eq!(mini_game(vec![]).winner(), None);
+    }
+}
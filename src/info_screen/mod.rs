@@ -1,73 +1,127 @@
 use crate::prelude::*;
 use bevy::prelude::*;
+use bevy_kira_audio::Audio;
+
+const ROULETTE_STEPS: usize = 14;
+const ROULETTE_DELAY: f32 = 0.06;
+const ROULETTE_SLOWDOWN: f32 = 0.4;
+const ROULETTE_HOLD: f32 = 0.8;
 
 #[derive(Component)]
 pub struct LocalEntity;
 
+pub struct Roulette {
+    names: Vec<&'static str>,
+    step: usize,
+    time: f32,
+}
+
+impl Roulette {
+    pub fn new(rotation: &[&'static str], landing: &'static str) -> Self {
+        let mut names: Vec<&'static str> = rotation
+            .iter()
+            .copied()
+            .cycle()
+            .take(ROULETTE_STEPS)
+            .collect();
+        if names.last() == Some(&landing) {
+            names.pop();
+        }
+        names.push(landing);
+        Self {
+            names,
+            step: 0,
+            time: 0.,
+        }
+    }
+
+    fn delay(&self) -> f32 {
+        if self.step + 1 >= self.names.len() {
+            ROULETTE_HOLD
+        } else {
+            let progress = self.step as f32 / self.names.len() as f32;
+            ROULETTE_DELAY + ROULETTE_SLOWDOWN * progress.powi(3)
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.names[self.step]
+    }
+}
+
 pub struct InfoScreen {
     active: bool,
+    spun: bool,
     mini_game: MiniGameState,
+    roulette: Option<Roulette>,
 }
 
 #[derive(Component)]
 pub struct InfoScreenText;
 
+#[derive(Component)]
+pub struct InfoScreenTitle;
+
 pub struct InfoScreenPlugin;
 
 impl Plugin for InfoScreenPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(InfoScreen {
             active: false,
+            spun: false,
             mini_game: MiniGameState::Inactive,
+            roulette: None,
         })
         .add_system_set(SystemSet::on_enter(GameState::InfoScreen).with_system(enter))
         .add_system_set(SystemSet::on_exit(GameState::InfoScreen).with_system(exit))
-        .add_system(update);
+        .add_system(update)
+        .add_system(update_roulette)
+        .add_system(reset);
     }
 }
 
 pub fn enter(
-    game: Res<Game>,
+    mut game: ResMut<Game>,
+    players: Res<Players>,
     mut info_screen: ResMut<InfoScreen>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mini_games: Res<MiniGames>,
+    ruleset: Res<Ruleset>,
+    history: Res<History>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     info_screen.active = true;
-    if game.duel {
-        info_screen.mini_game = MiniGameState::Duel;
-    } else {
-        info_screen.mini_game = match game.turn % 3 {
-            0 => MiniGameState::Pong,
-            1 => MiniGameState::Boats,
-            _ => MiniGameState::Rps,
-        };
+    let mini_game = mini_games.next(
+        &mut game,
+        &ruleset,
+        &history.mini_games(),
+        game_rng.stream(RngStream::MiniGame),
+    );
+    info_screen.mini_game = MiniGameState::Playing(mini_game);
+    if !info_screen.spun {
+        let rotation: Vec<&'static str> = mini_games
+            .rotation()
+            .into_iter()
+            .map(|id| mini_games.name(id))
+            .collect();
+        info_screen.roulette = Some(Roulette::new(&rotation, mini_games.name(mini_game)));
+        info_screen.spun = true;
     }
-    let mut mini_game_name = String::new();
-    let mut mini_game_description = String::new();
-    match info_screen.mini_game {
-        MiniGameState::Boats => {
-            mini_game_name = "Cannon Ball Dodge".into();
-            mini_game_description =
-                "Dodge the falling cannon balls!\nStart with 6 coins!\nIf you get hit you lose 2 coins!\nGame goes until someone runs out of coins!\n\nControls:\nWASD - Move".into();
-        }
-        MiniGameState::Rps => {
-            mini_game_name = "Rock Paper Scissors".into();
-            mini_game_description =
-                "Rock, Paper, Scissors, SHOOT!\nReact to your opponent!\nMake your selection quickly AFTER them!\nPlay 3 rounds, round winner gets 2 coins!\n\nControls:\nR - Rock\nP - Paper\nS - Scissors".into();
-        }
-        MiniGameState::Duel => {
-            mini_game_name = "Duel".into();
-            mini_game_description =
-                "Attack your opponent! Defend against their attack!\nDefend right before an attack to stun your opponent!\nCancel your attack by releasing the A button!\nBait your opponent into defending prematurely!\nSteal coins if you successfully attack them.\n\nControls:\nA - Hold to attack\nD - Hold to defend".into();
-        }
-        MiniGameState::Pong => {
-            mini_game_name = "Pong".into();
-            mini_game_description =
-                "Hit the puck with your paddle!\nIf the puck gets by your opponent, gain 2 coins!\n3 pucks total"
-                    .into();
+    let keys = players.keys(game.challenger);
+    let (mini_game_name, mini_game_description) = match mini_games.get(mini_game) {
+        Some(info) => {
+            let mut description =
+                format!("{}\n{} players", info.description(&keys), info.players());
+            let controls = info.controls(&keys);
+            if !controls.is_empty() {
+                description.push_str("\n\nControls:\n");
+                description.push_str(&controls.join("\n"));
+            }
+            (info.name(), description)
         }
-        MiniGameState::Inactive => {}
-    }
+        None => (mini_game.0, String::new()),
+    };
     commands
         .spawn_bundle(NodeBundle {
             style: Style {
@@ -159,7 +213,8 @@ pub fn enter(
                             ),
                             ..Default::default()
                         })
-                        .insert(InfoScreenText);
+                        .insert(InfoScreenText)
+                        .insert(InfoScreenTitle);
                 });
         })
         .insert(LocalEntity);
@@ -171,31 +226,79 @@ pub fn exit(
     destroy_query: Query<Entity, With<LocalEntity>>,
 ) {
     info_screen.active = false;
+    info_screen.roulette = None;
     for entity in destroy_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
 }
 
+pub fn update_roulette(
+    mut info_screen: ResMut<InfoScreen>,
+    dialogue: Res<Dialogue>,
+    time: Res<Clock>,
+    audio: Res<Audio>,
+    asset_library: Res<AssetLibrary>,
+    mut title_query: Query<&mut Text, With<InfoScreenTitle>>,
+) {
+    if dialogue.busy() {
+        return;
+    }
+    let finished = if let Some(roulette) = info_screen.roulette.as_mut() {
+        if roulette.time == 0. && roulette.step == 0 {
+            audio.play(asset_library.audio("move"));
+        }
+        roulette.time += time.delta_seconds();
+        if roulette.time >= roulette.delay() {
+            roulette.time = 0.;
+            roulette.step += 1;
+            if roulette.step + 1 == roulette.names.len() {
+                audio.play(asset_library.audio("diceding"));
+            } else if roulette.step < roulette.names.len() {
+                audio.play(asset_library.audio("move"));
+            }
+        }
+        let finished = roulette.step >= roulette.names.len();
+        if !finished {
+            for mut text in title_query.iter_mut() {
+                text.sections[0].value = roulette.name().into();
+            }
+        }
+        finished
+    } else {
+        false
+    };
+    if finished {
+        info_screen.roulette = None;
+    }
+}
+
+pub fn reset(mut reset: EventReader<GameReset>, mut info_screen: ResMut<InfoScreen>) {
+    for _ in reset.iter() {
+        info_screen.spun = false;
+    }
+}
+
 pub fn update(
     mut game: ResMut<Game>,
-    info_screen: Res<InfoScreen>,
+    mut info_screen: ResMut<InfoScreen>,
     mut mini_game: ResMut<MiniGame>,
     mut input: ResMut<Input<KeyCode>>,
     mut game_state: ResMut<State<GameState>>,
     mut mini_game_state: ResMut<State<MiniGameState>>,
     mut dialogue: ResMut<Dialogue>,
-    mut text_query: Query<&mut Visibility, With<InfoScreenText>>,
+    mut text_query: Query<(&mut Visibility, Option<&InfoScreenTitle>), With<InfoScreenText>>,
 ) {
     if dialogue.busy() {
-        for mut visibility in text_query.iter_mut() {
+        for (mut visibility, _) in text_query.iter_mut() {
             visibility.is_visible = false;
         }
         return;
     }
-    for mut visibility in text_query.iter_mut() {
-        visibility.is_visible = true;
+    let spinning = info_screen.roulette.is_some();
+    for (mut visibility, title) in text_query.iter_mut() {
+        visibility.is_visible = !spinning || title.is_some();
     }
-    if info_screen.active && !dialogue.busy() {
+    if info_screen.active && !spinning {
         if input.just_pressed(KeyCode::Space) {
             if game.practice_first_message {
                 dialogue.add(DialogueEntry {
@@ -208,6 +311,7 @@ pub fn update(
                 });
                 game.practice_first_message = false;
             } else {
+                info_screen.spun = false;
                 mini_game.practice = false;
                 mini_game_state.set(info_screen.mini_game).unwrap();
                 game_state.set(GameState::MiniGame).unwrap();
@@ -20,18 +20,20 @@ impl Plugin for IntroPlugin {
     }
 }
 
-pub fn enter(mut intro: ResMut<IntroState>, difficulty: Res<Difficulty>, mut game: ResMut<Game>) {
+pub fn enter(
+    mut intro: ResMut<IntroState>,
+    difficulty: Res<Difficulty>,
+    mut game: ResMut<Game>,
+    mut players: CoinLedger,
+) {
     //audio.play_looped(asset_library.audio("music"));
     *intro = IntroState::Dialogue1;
-    match difficulty.as_ref() {
-        Difficulty::Normal => {
-            game.my_coins = 25;
-            game.your_coins = 25;
-        }
-        Difficulty::Hard => {
-            game.my_coins = 15;
-            game.your_coins = 15;
-        }
+    let coins = match difficulty.as_ref() {
+        Difficulty::Normal => 25,
+        Difficulty::Hard => 15,
+    };
+    for player in 0..players.len() {
+        players.set(player, coins, CoinReason::Start);
     }
     if matches!(*difficulty, Difficulty::Hard) {
         game.practice_first_message = false;
@@ -83,7 +85,7 @@ pub fn update(
             *intro = IntroState::DiceRoll1;
         }
         IntroState::DiceRoll1 => {
-            dice_start.send(DiceRollStart { value: 10 });
+            dice_start.send(DiceRollStart::scripted(Die::D10, 10));
             game.dice_roll = true;
             *intro = IntroState::Dialogue2;
         }
@@ -99,7 +101,10 @@ pub fn update(
             *intro = IntroState::DiceRoll2;
         }
         IntroState::DiceRoll2 => {
-            dice_start.send(DiceRollStart { value: 3 });
+            dice_start.send(DiceRollStart {
+                interactive: true,
+                ..DiceRollStart::scripted(Die::D6, 3)
+            });
             game.dice_roll = true;
             *intro = IntroState::Dialogue3;
         }
@@ -6,31 +6,86 @@ use common::CommonPlugin;
 use cont::ContinuePlugin;
 use end_game::EndGamePlugin;
 use ending::EndingPlugin;
+use gallery::GalleryPlugin;
+use history::HistoryPlugin;
 use info_screen::InfoScreenPlugin;
 use intro::IntroPlugin;
 use loading::LoadingPlugin;
 use menu::MenuPlugin;
 use mini_game::MiniGamePlugin;
 use music::MusicPlugin;
+use players::ledger::LedgerPlugin;
+use players::Players;
+use save::SavePlugin;
+use serde::{Deserialize, Serialize};
 use setup::SetupPlugin;
+use stats::StatsPlugin;
 
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub enum Difficulty {
     Normal,
     Hard,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Ruleset {
+    Story,
+    FairPlay,
+}
+
+impl Ruleset {
+    pub fn rigged(&self) -> bool {
+        matches!(*self, Ruleset::Story)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum GameMode {
+    Elimination,
+    Stars { turn_limit: u32 },
+}
+
+impl Default for GameMode {
+    fn default() -> Self {
+        GameMode::Elimination
+    }
+}
+
+impl GameMode {
+    pub const FINAL_TURNS: u32 = 3;
+
+    pub fn turn_limit(&self) -> Option<u32> {
+        match *self {
+            GameMode::Elimination => None,
+            GameMode::Stars { turn_limit } => Some(turn_limit),
+        }
+    }
+
+    pub fn stars(&self) -> bool {
+        matches!(*self, GameMode::Stars { .. })
+    }
+
+    pub fn final_stretch(&self, turn: u32) -> bool {
+        self.turn_limit().map_or(false, |limit| {
+            turn + GameMode::FINAL_TURNS > limit && turn <= limit
+        })
+    }
+
+    pub fn over(&self, turn: u32) -> bool {
+        self.turn_limit().map_or(false, |limit| turn > limit)
+    }
+}
+
 #[derive(Default)]
 pub struct Game {
-    pub my_color: Color,
-    pub your_color: Color,
-    pub my_coins: u32,
-    pub your_coins: u32,
     pub turn: u32,
+    pub challenger: usize,
     pub reset_timer: f32,
     pub duel: bool,
     pub dice_roll: bool,
     pub practice_first_message: bool,
     pub rps_early_message: bool,
+    pub mini_game: Option<MiniGameId>,
 }
 
 pub struct GameReset;
@@ -49,6 +104,8 @@ pub enum GameState {
     EndGame,
     Ending,
     Continue,
+    Stats,
+    Gallery,
 }
 
 #[derive(Component)]
@@ -70,6 +127,9 @@ fn main() {
         .insert_resource(ClearColor(Color::BLACK))
         .insert_resource(Game::default())
         .insert_resource(Difficulty::Normal)
+        .insert_resource(Ruleset::Story)
+        .insert_resource(GameMode::default())
+        .insert_resource(Players::default())
         .add_event::<GameReset>()
         .add_event::<GameResetSend>()
         .add_state(GameState::Loading)
@@ -85,8 +145,13 @@ fn main() {
         .add_plugin(SetupPlugin)
         .add_plugin(EndingPlugin)
         .add_plugin(ContinuePlugin)
+        .add_plugin(StatsPlugin)
+        .add_plugin(GalleryPlugin)
         .add_plugin(MenuPlugin)
         .add_plugin(MusicPlugin)
+        .add_plugin(SavePlugin)
+        .add_plugin(LedgerPlugin)
+        .add_plugin(HistoryPlugin)
         .add_startup_system(init)
         .add_system(camera_scale.label(GameLabel::CameraScale))
         .add_system(start_game)
@@ -114,13 +179,13 @@ pub fn start_game(
     mut game_state: ResMut<State<GameState>>,
 ) {
     for _ in asset_library_ready.iter() {
-        game.my_color = Color::rgba(1., 0.7, 0.7, 1.0);
-        game.your_color = Color::YELLOW;
         game.turn = 1;
+        game.challenger = 1;
         game.duel = false;
         game.dice_roll = false;
         game.practice_first_message = true;
         game.rps_early_message = true;
+        game.mini_game = None;
         game_state.set(GameState::Menu).unwrap();
     }
 }
@@ -132,7 +197,7 @@ pub fn reset_game(
     entities: Query<Entity, Without<Camera>>,
     mut commands: Commands,
     mut game: ResMut<Game>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut reset_event: EventWriter<GameReset>,
     mut reset_send_event: EventReader<GameResetSend>,
 ) {
@@ -178,17 +243,30 @@ pub mod common;
 pub mod cont;
 pub mod end_game;
 pub mod ending;
+pub mod gallery;
+pub mod history;
 pub mod info_screen;
 pub mod intro;
 pub mod loading;
 pub mod menu;
 pub mod mini_game;
 pub mod music;
+pub mod players;
+pub mod save;
 pub mod setup;
+pub mod stats;
 
 pub mod prelude {
     pub use super::{
-        board::prelude::*, common::prelude::*, mini_game::prelude::*, Difficulty, Game, GameCamera,
-        GameLabel, GameReset, GameResetSend, GameState,
+        board::prelude::*,
+        common::prelude::*,
+        history::{History, HistoryEvent},
+        mini_game::prelude::*,
+        players::{
+            ledger::{CoinLedger, CoinReason, CoinsChanged},
+            *,
+        },
+        Difficulty, Game, GameCamera, GameLabel, GameMode, GameReset, GameResetSend, GameState,
+        Ruleset,
     };
 }
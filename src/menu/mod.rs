@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::save::{load_save, save_exists};
 use bevy::prelude::*;
 use bevy_kira_audio::Audio;
 
@@ -7,6 +8,9 @@ pub struct MenuPlugin;
 #[derive(Component)]
 pub struct MenuEntity;
 
+#[derive(Component)]
+pub struct MenuMessage;
+
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(SystemSet::on_enter(GameState::Menu).with_system(enter))
@@ -15,7 +19,7 @@ impl Plugin for MenuPlugin {
     }
 }
 
-pub fn enter(mut commands: Commands, asset_library: Res<AssetLibrary>) {
+pub fn enter(mut commands: Commands, asset_library: Res<AssetLibrary>, replay: Res<Replay>) {
     commands
         .spawn_bundle(SpriteBundle {
             texture: asset_library.image("menu_bg"),
@@ -33,7 +37,7 @@ pub fn enter(mut commands: Commands, asset_library: Res<AssetLibrary>) {
     commands
         .spawn_bundle(Text2dBundle {
             text: Text::with_section(
-                "Press SPACE to Play",
+                "Press SPACE to Play\nPress G for Mini Games",
                 TextStyle {
                     color: Color::BLACK,
                     font: asset_library.font("game"),
@@ -48,6 +52,30 @@ pub fn enter(mut commands: Commands, asset_library: Res<AssetLibrary>) {
             ..Default::default()
         })
         .insert(MenuEntity);
+    let message = if save_exists() && !replay.is_active() {
+        "Press C to Continue"
+    } else {
+        ""
+    };
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text::with_section(
+                message,
+                TextStyle {
+                    color: Color::BLACK,
+                    font: asset_library.font("game"),
+                    font_size: 16.,
+                },
+                TextAlignment {
+                    vertical: VerticalAlign::Center,
+                    horizontal: HorizontalAlign::Center,
+                },
+            ),
+            transform: Transform::from_xyz(0., -125., 0.3),
+            ..Default::default()
+        })
+        .insert(MenuEntity)
+        .insert(MenuMessage);
 }
 
 pub fn exit(mut commands: Commands, query: Query<Entity, With<MenuEntity>>) {
@@ -61,9 +89,54 @@ pub fn update(
     mut game_state: ResMut<State<GameState>>,
     asset_library: Res<AssetLibrary>,
     audio: Res<Audio>,
+    board_layouts: Res<Assets<BoardLayout>>,
+    mut game: ResMut<Game>,
+    mut board: ResMut<Board>,
+    mut players: ResMut<Players>,
+    mut difficulty: ResMut<Difficulty>,
+    mut ruleset: ResMut<Ruleset>,
+    mut mode: ResMut<GameMode>,
+    mut history: ResMut<History>,
+    mut message_query: Query<&mut Text, With<MenuMessage>>,
+    replay: Res<Replay>,
 ) {
-    if input.just_pressed(KeyCode::Space) {
+    if let Some(err) = &board.layout_error {
+        for mut text in message_query.iter_mut() {
+            text.sections[0].value = format!("Could not load the board: {}", err);
+        }
+    }
+    if input.just_pressed(KeyCode::Space) && board.layout_error.is_none() {
         game_state.set(GameState::Setup).unwrap();
         audio.play(asset_library.audio("dialogue"));
+    } else if input.just_pressed(KeyCode::G) {
+        game_state.set(GameState::Gallery).unwrap();
+        audio.play(asset_library.audio("dialogue"));
+    } else if input.just_pressed(KeyCode::C)
+        && save_exists()
+        && !replay.is_active()
+        && board.layout_error.is_none()
+    {
+        let layout = board_layouts.get(asset_library.board("default"));
+        match load_save(layout) {
+            Ok(save) => {
+                save.apply(
+                    &mut game,
+                    &mut board,
+                    &mut players,
+                    &mut difficulty,
+                    &mut ruleset,
+                    &mut mode,
+                    &mut history,
+                );
+                game_state.set(GameState::Board).unwrap();
+                audio.play(asset_library.audio("dialogue"));
+            }
+            Err(err) => {
+                error!("failed to load save: {}", err);
+                for mut text in message_query.iter_mut() {
+                    text.sections[0].value = format!("Could not continue: {}", err);
+                }
+            }
+        }
     }
 }
@@ -13,11 +13,7 @@ impl Boat {
     pub fn hit(&mut self) -> bool {
         if self.coins > 0 && self.invulnerable_timer == 0. {
             self.invulnerable_timer = 1.;
-            if self.coins < 2 {
-                self.coins = 0;
-            } else {
-                self.coins -= 2;
-            }
+            self.coins = self.coins.saturating_sub(2);
             true
         } else {
             false
@@ -29,22 +25,25 @@ pub struct BoatPlugin;
 
 impl Plugin for BoatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_update(MiniGameState::Boats).with_system(update));
+        app.add_system_set(
+            SystemSet::on_update(MiniGameState::Playing(super::ID)).with_system(update),
+        );
     }
 }
 
 pub fn update(
     game: Res<Game>,
+    players: Res<Players>,
     mut boat_query: Query<(Entity, &mut Transform, &Collision, &mut Boat, &mut Sprite)>,
     collision_query: Res<CollisionQuery>,
-    timer: Res<Time>,
+    timer: Res<Clock>,
     mini_game: Res<MiniGame>,
 ) {
     for (_, _, _, boat, mut sprite) in boat_query.iter_mut() {
         let color = if boat.my_boat {
-            game.my_color
+            players.color(Players::HOST)
         } else {
-            game.your_color
+            players.color(game.challenger)
         };
         sprite.color = color;
     }
@@ -98,9 +97,9 @@ pub fn update(
         transform.translation.y = transform.translation.y.min(60.).max(-60.);
         boat.invulnerable_timer = (boat.invulnerable_timer - timer.delta_seconds()).max(0.);
         let mut color = if boat.my_boat {
-            game.my_color
+            players.color(Players::HOST)
         } else {
-            game.your_color
+            players.color(game.challenger)
         };
         if boat.invulnerable_timer > 0. {
             color.set_a(0.2);
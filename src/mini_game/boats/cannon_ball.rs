@@ -27,11 +27,12 @@ impl Plugin for CannonBallPlugin {
 pub fn update(
     mut commands: Commands,
     mut query: Query<(Entity, &mut CannonBall, &mut Transform, &mut Sprite)>,
-    time: Res<Time>,
+    time: Res<Clock>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::Boats);
     for (entity, mut cannon_ball, mut transform, mut sprite) in query.iter_mut() {
         if !cannon_ball.dir_set {
             let angle = Vec2::angle_between(Vec2::new(-1., 0.), transform.translation.truncate())
@@ -68,6 +69,7 @@ pub fn hit_boats(
     collision_query: Res<CollisionQuery>,
     mini_game: Res<MiniGame>,
     audio: Res<Audio>,
+    mut camera_shake: EventWriter<CameraShake>,
     asset_library: Res<AssetLibrary>,
 ) {
     if !mini_game.active {
@@ -85,6 +87,7 @@ pub fn hit_boats(
                 if let Ok(mut boat) = boat_query.get_mut(response.entity) {
                     if boat.hit() {
                         audio.play(asset_library.audio("boathit"));
+                        camera_shake.send(CameraShake { trauma: 0.5 });
                     }
                 }
             }
@@ -12,12 +12,14 @@ pub struct EnemyBoatPlugin;
 
 impl Plugin for EnemyBoatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_update(MiniGameState::Boats).with_system(update));
+        app.add_system_set(
+            SystemSet::on_update(MiniGameState::Playing(super::ID)).with_system(update),
+        );
     }
 }
 
-pub fn update(mut boat_query: Query<(&mut Boat, &mut EnemyBoat)>) {
-    let mut rng = rand::thread_rng();
+pub fn update(mut boat_query: Query<(&mut Boat, &mut EnemyBoat)>, mut game_rng: ResMut<GameRng>) {
+    let rng = game_rng.stream(RngStream::Boats);
     for (mut boat, mut enemy_boat) in boat_query.iter_mut() {
         if enemy_boat.angle == 0. || rng.gen_bool(0.1) {
             enemy_boat.angle = rng.gen_range(0.0..360.0f32).to_radians();
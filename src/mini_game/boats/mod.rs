@@ -7,6 +7,28 @@ use enemy_boat::{EnemyBoat, EnemyBoatPlugin};
 use player_boat::{PlayerBoat, PlayerBoatPlugin};
 use rand::prelude::*;
 
+pub const ID: MiniGameId = MiniGameId("boats");
+
+pub struct BoatsInfo;
+
+impl MiniGameInfo for BoatsInfo {
+    fn id(&self) -> MiniGameId {
+        ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Cannon Ball Dodge"
+    }
+
+    fn description(&self, _keys: &KeyBindings) -> String {
+        "Dodge the falling cannon balls!\nStart with 6 coins!\nIf you get hit you lose 2 coins!\nGame goes until someone runs out of coins!".into()
+    }
+
+    fn controls(&self, keys: &KeyBindings) -> Vec<String> {
+        vec![format!("{} - Move", keys.movement_label())]
+    }
+}
+
 pub struct BoatsPlugin;
 
 impl Plugin for BoatsPlugin {
@@ -15,9 +37,13 @@ impl Plugin for BoatsPlugin {
             .add_plugin(BoatPlugin)
             .add_plugin(PlayerBoatPlugin)
             .add_plugin(EnemyBoatPlugin)
-            .add_system_set(SystemSet::on_enter(MiniGameState::Boats).with_system(enter))
-            .add_system_set(SystemSet::on_update(MiniGameState::Boats).with_system(spawn_cannons))
-            .add_system_set(SystemSet::on_update(MiniGameState::Boats).with_system(update_coins));
+            .add_mini_game(BoatsInfo, enter, super::teardown)
+            .add_system_set(
+                SystemSet::on_update(MiniGameState::Playing(ID)).with_system(spawn_cannons),
+            )
+            .add_system_set(
+                SystemSet::on_update(MiniGameState::Playing(ID)).with_system(update_coins),
+            );
     }
 }
 
@@ -26,9 +52,13 @@ pub fn enter(
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
     difficulty: Res<Difficulty>,
+    ruleset: Res<Ruleset>,
+    game: Res<Game>,
+    players: Res<Players>,
 ) {
     mini_game.display_prefix = "+".into();
-    let my_size = if mini_game.practice {
+    let fair = mini_game.practice || !ruleset.rigged();
+    let my_size = if fair {
         1.
     } else {
         match *difficulty {
@@ -36,7 +66,7 @@ pub fn enter(
             Difficulty::Hard => 0.5,
         }
     };
-    let your_size = if mini_game.practice {
+    let your_size = if fair {
         1.
     } else {
         match *difficulty {
@@ -45,9 +75,13 @@ pub fn enter(
         }
     };
     let player_hitbox_size = 0.8;
-    let my_hitbox_size = match *difficulty {
-        Difficulty::Normal => 1.2,
-        Difficulty::Hard => 0.8,
+    let my_hitbox_size = if !ruleset.rigged() {
+        player_hitbox_size
+    } else {
+        match *difficulty {
+            Difficulty::Normal => 1.2,
+            Difficulty::Hard => 0.8,
+        }
     };
     commands
         .spawn_bundle(SpriteBundle {
@@ -57,13 +91,13 @@ pub fn enter(
         })
         .insert(MiniGameEntity);
 
-    commands
-        .spawn_bundle(SpriteBundle {
-            texture: asset_library.image("boat"),
-            transform: Transform::from_xyz(-100.0, 0.0, 0.1)
-                .with_scale(Vec3::new(your_size, your_size, 1.)),
-            ..Default::default()
-        })
+    let mut your_boat = commands.spawn_bundle(SpriteBundle {
+        texture: asset_library.image("boat"),
+        transform: Transform::from_xyz(-100.0, 0.0, 0.1)
+            .with_scale(Vec3::new(your_size, your_size, 1.)),
+        ..Default::default()
+    });
+    your_boat
         .insert(Collision {
             shape: CollisionShape::Rect {
                 size: Vec2::new(
@@ -78,16 +112,21 @@ pub fn enter(
             coins: 6,
             ..Default::default()
         })
-        .insert(PlayerBoat)
         .insert(MiniGameEntity);
+    if players.is_human(game.challenger) {
+        your_boat.insert(PlayerBoat {
+            player: game.challenger,
+        });
+    } else {
+        your_boat.insert(EnemyBoat::default());
+    }
 
-    commands
-        .spawn_bundle(SpriteBundle {
-            texture: asset_library.image("boat"),
-            transform: Transform::from_xyz(100.0, 0.0, 0.1)
-                .with_scale(Vec3::new(my_size, my_size, 1.)),
-            ..Default::default()
-        })
+    let mut my_boat = commands.spawn_bundle(SpriteBundle {
+        texture: asset_library.image("boat"),
+        transform: Transform::from_xyz(100.0, 0.0, 0.1).with_scale(Vec3::new(my_size, my_size, 1.)),
+        ..Default::default()
+    });
+    my_boat
         .insert(Collision {
             shape: CollisionShape::Rect {
                 size: Vec2::new(
@@ -102,8 +141,14 @@ pub fn enter(
             coins: 6,
             ..Default::default()
         })
-        .insert(EnemyBoat::default())
         .insert(MiniGameEntity);
+    if players.is_human(Players::HOST) {
+        my_boat.insert(PlayerBoat {
+            player: Players::HOST,
+        });
+    } else {
+        my_boat.insert(EnemyBoat::default());
+    }
 }
 
 pub fn spawn_cannons(
@@ -112,6 +157,7 @@ pub fn spawn_cannons(
     mini_game: Res<MiniGame>,
     difficulty: Res<Difficulty>,
     audio: Res<Audio>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     if !mini_game.active {
         return;
@@ -120,7 +166,7 @@ pub fn spawn_cannons(
         Difficulty::Normal => 0.05,
         Difficulty::Hard => 0.08,
     };
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::Boats);
     if rng.gen_bool(spawn_chance) {
         audio.play(asset_library.audio("cannon"));
         let angle = rng.gen_range(0.0..360.0f32).to_radians();
@@ -154,6 +200,7 @@ pub fn spawn_cannons(
 }
 
 pub fn update_coins(
+    game: Res<Game>,
     mut mini_game: ResMut<MiniGame>,
     boat_query: Query<&Boat>,
     mut mini_game_finish: EventWriter<MiniGameFinish>,
@@ -173,8 +220,10 @@ pub fn update_coins(
     }
     if boats_initialized && (my_coins == 0 || your_coins == 0) {
         mini_game_finish.send(MiniGameFinish {
-            my_coins: my_coins as i32,
-            your_coins: your_coins as i32,
+            results: vec![
+                (Players::HOST, my_coins as i32),
+                (game.challenger, your_coins as i32),
+            ],
         });
     }
 }
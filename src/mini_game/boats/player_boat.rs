@@ -3,37 +3,43 @@ use crate::prelude::*;
 use bevy::prelude::*;
 
 #[derive(Component)]
-pub struct PlayerBoat;
+pub struct PlayerBoat {
+    pub player: usize,
+}
 
 pub struct PlayerBoatPlugin;
 
 impl Plugin for PlayerBoatPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_update(MiniGameState::Boats).with_system(update));
+        app.add_system_set(
+            SystemSet::on_update(MiniGameState::Playing(super::ID)).with_system(update),
+        );
     }
 }
 
 pub fn update(
-    mut boat_query: Query<&mut Boat, With<PlayerBoat>>,
+    mut boat_query: Query<(&mut Boat, &PlayerBoat)>,
     input: Res<Input<KeyCode>>,
     difficulty: Res<Difficulty>,
+    players: Res<Players>,
 ) {
     let speed = match *difficulty {
         Difficulty::Normal => 2.0,
         Difficulty::Hard => 1.5,
     };
-    for mut boat in boat_query.iter_mut() {
+    for (mut boat, player_boat) in boat_query.iter_mut() {
+        let keys = players.keys(player_boat.player);
         boat.movement = Vec2::new(0., 0.);
-        if input.pressed(KeyCode::S) {
+        if input.pressed(keys.down) {
             boat.movement.y -= 1.;
         }
-        if input.pressed(KeyCode::W) {
+        if input.pressed(keys.up) {
             boat.movement.y += 1.;
         }
-        if input.pressed(KeyCode::A) {
+        if input.pressed(keys.left) {
             boat.movement.x -= 1.;
         }
-        if input.pressed(KeyCode::D) {
+        if input.pressed(keys.right) {
             boat.movement.x += 1.;
         }
         boat.movement = boat.movement.normalize_or_zero() * speed;
@@ -36,6 +36,38 @@ pub struct Duelist {
 #[derive(Component)]
 pub struct DuelHud;
 
+pub const ID: MiniGameId = MiniGameId("duel");
+
+pub struct DuelInfo;
+
+impl MiniGameInfo for DuelInfo {
+    fn id(&self) -> MiniGameId {
+        ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Duel"
+    }
+
+    fn description(&self, keys: &KeyBindings) -> String {
+        format!(
+            "Attack your opponent! Defend against their attack!\nDefend right before an attack to stun your opponent!\nCancel your attack by releasing the {} button!\nBait your opponent into defending prematurely!\nSteal coins if you successfully attack them.",
+            key_name(keys.attack)
+        )
+    }
+
+    fn controls(&self, keys: &KeyBindings) -> Vec<String> {
+        vec![
+            format!("{} - Hold to attack", key_name(keys.attack)),
+            format!("{} - Hold to defend", key_name(keys.defend)),
+        ]
+    }
+
+    fn rotation(&self) -> bool {
+        false
+    }
+}
+
 pub struct DuelPlugin;
 
 impl Plugin for DuelPlugin {
@@ -48,14 +80,15 @@ impl Plugin for DuelPlugin {
             your_coin_penalty: 1,
             player_cooldown_percent: 1.,
         })
-        .add_system_set(SystemSet::on_enter(MiniGameState::Duel).with_system(init))
-        .add_system_set(SystemSet::on_update(MiniGameState::Duel).with_system(update))
-        .add_system_set(SystemSet::on_update(MiniGameState::Duel).with_system(ai));
+        .add_mini_game(DuelInfo, init, super::teardown)
+        .add_system_set(SystemSet::on_update(MiniGameState::Playing(ID)).with_system(update))
+        .add_system_set(SystemSet::on_update(MiniGameState::Playing(ID)).with_system(ai));
     }
 }
 
 pub fn init(
     game: Res<Game>,
+    players: Res<Players>,
     mut mini_game: ResMut<MiniGame>,
     mut duel: ResMut<Duel>,
     mut commands: Commands,
@@ -83,15 +116,15 @@ pub fn init(
         duel.my_coins = 100;
         duel.your_coins = 100;
     } else {
-        duel.my_coins = game.my_coins;
-        duel.your_coins = game.your_coins;
+        duel.my_coins = players.coins(Players::HOST);
+        duel.your_coins = players.coins(game.challenger);
     }
     commands
         .spawn_bundle(SpriteSheetBundle {
             texture_atlas: asset_library.texture_atlas("duel"),
             transform: Transform::from_xyz(-25., 0., 0.),
             sprite: TextureAtlasSprite {
-                color: game.your_color,
+                color: players.color(game.challenger),
                 ..Default::default()
             },
             ..Default::default()
@@ -108,7 +141,7 @@ pub fn init(
             texture_atlas: asset_library.texture_atlas("duel"),
             sprite: TextureAtlasSprite {
                 flip_x: true,
-                color: game.my_color,
+                color: players.color(Players::HOST),
                 ..Default::default()
             },
             transform: Transform::from_xyz(25., 0., 0.),
@@ -164,14 +197,16 @@ pub fn init(
 
 pub fn update(
     game: Res<Game>,
+    players: Res<Players>,
     mut duel: ResMut<Duel>,
     input: Res<Input<KeyCode>>,
     mut duelist_query: Query<(&mut Duelist, &mut Transform, &mut TextureAtlasSprite)>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut mini_game_finish: EventWriter<MiniGameFinish>,
     mut mini_game: ResMut<MiniGame>,
     mut hud_query: Query<&mut Text, With<DuelHud>>,
     audio: Res<Audio>,
+    mut camera_shake: EventWriter<CameraShake>,
     asset_library: Res<AssetLibrary>,
     difficulty: Res<Difficulty>,
 ) {
@@ -181,6 +216,10 @@ pub fn update(
         }
         return;
     }
+    let keys = players.keys(game.challenger);
+    let host_keys = players.keys(Players::HOST);
+    let challenger_human = players.is_human(game.challenger);
+    let host_human = players.is_human(Players::HOST);
     let mut player_attacking = false;
     let mut player_defend_time = 0.;
     let mut player_hit = false;
@@ -200,17 +239,24 @@ pub fn update(
         } else {
             ATTACK_PREP_TIME
         };
-        if duelist.player {
-            if input.just_pressed(KeyCode::A) {
+        let duelist_keys = if duelist.player {
+            Some(keys).filter(|_| challenger_human)
+        } else if host_human {
+            Some(host_keys)
+        } else {
+            None
+        };
+        if let Some(keys) = duelist_keys {
+            if input.just_pressed(keys.attack) {
                 duelist.wants_to_attack = true;
             }
-            if input.just_pressed(KeyCode::D) {
+            if input.just_pressed(keys.defend) {
                 duelist.wants_to_defend = true;
             }
-            if !input.pressed(KeyCode::A) {
+            if !input.pressed(keys.attack) {
                 duelist.wants_to_attack = false;
             }
-            if !input.pressed(KeyCode::D) {
+            if !input.pressed(keys.defend) {
                 duelist.wants_to_defend = false;
             }
         }
@@ -303,9 +349,11 @@ pub fn update(
     if player_attacking {
         if myself_defend_time == 0. {
             audio.play(asset_library.audio("duelhit"));
+            camera_shake.send(CameraShake { trauma: 0.5 });
             myself_hit = true;
         } else {
             audio.play(asset_library.audio("duelblock"));
+            camera_shake.send(CameraShake { trauma: 0.3 });
             if myself_defend_time < PARRY_TIME {
                 myself_defended = true;
                 player_stun = true;
@@ -315,9 +363,11 @@ pub fn update(
     if myself_attacking {
         if player_defend_time == 0. {
             audio.play(asset_library.audio("duelhit"));
+            camera_shake.send(CameraShake { trauma: 0.5 });
             player_hit = true;
         } else {
             audio.play(asset_library.audio("duelblock"));
+            camera_shake.send(CameraShake { trauma: 0.3 });
             if player_defend_time < PARRY_TIME {
                 player_defended = true;
                 myself_stun = true;
@@ -365,28 +415,26 @@ pub fn update(
         }
     }
     if player_hit && duel.your_coins > 0 {
-        if duel.your_coin_penalty > duel.your_coins {
-            duel.your_coins = 0;
-            duel.my_coins += 1;
-        } else {
-            duel.your_coins -= duel.your_coin_penalty;
-            duel.my_coins += 1;
-        }
+        duel.your_coins = duel.your_coins.saturating_sub(duel.your_coin_penalty);
+        duel.my_coins += 1;
     }
     if myself_hit && duel.my_coins > 0 {
-        if duel.my_coin_penalty > duel.my_coins {
-            duel.my_coins = 0;
-            duel.your_coins += 1;
-        } else {
-            duel.my_coins -= duel.my_coin_penalty;
-            duel.your_coins += 1;
-        }
+        duel.my_coins = duel.my_coins.saturating_sub(duel.my_coin_penalty);
+        duel.your_coins += 1;
     }
     duel.time -= time.delta_seconds();
     if duel.time <= 0. || duel.your_coins == 0 || duel.my_coins == 0 {
         mini_game_finish.send(MiniGameFinish {
-            my_coins: duel.my_coins as i32 - game.my_coins as i32,
-            your_coins: duel.your_coins as i32 - game.your_coins as i32,
+            results: vec![
+                (
+                    Players::HOST,
+                    duel.my_coins as i32 - players.coins(Players::HOST) as i32,
+                ),
+                (
+                    game.challenger,
+                    duel.your_coins as i32 - players.coins(game.challenger) as i32,
+                ),
+            ],
         });
     }
     for mut text in hud_query.iter_mut() {
@@ -397,11 +445,16 @@ pub fn update(
 }
 
 pub fn ai(
+    game: Res<Game>,
     mini_game: Res<MiniGame>,
     mut duelist_query: Query<&mut Duelist>,
     difficulty: Res<Difficulty>,
+    players: Res<Players>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    if !mini_game.active {
+    let host_ai = !players.is_human(Players::HOST);
+    let challenger_ai = !players.is_human(game.challenger);
+    if !mini_game.active || !(host_ai || challenger_ai) {
         return;
     }
     let attack_chance: f32 = match difficulty.as_ref() {
@@ -413,47 +466,51 @@ pub fn ai(
         Difficulty::Hard => 0.03,
     };
     let mut duelist_count = 0;
-    let mut player_attack_time = 0.;
-    let mut player_defend_time = 0.;
-    let mut player_cooldown = 0.;
+    let mut attack_times = [0.; 2];
+    let mut defend_times = [0.; 2];
     for duelist in duelist_query.iter() {
         duelist_count += 1;
-        if duelist.player {
-            player_attack_time = duelist.attack_time;
-            player_defend_time = duelist.defend_time;
-            player_cooldown = duelist.defend_time;
-        }
+        attack_times[duelist.player as usize] = duelist.attack_time;
+        defend_times[duelist.player as usize] = duelist.defend_time;
     }
     if duelist_count == 2 {
-        let mut rng = rand::thread_rng();
+        let rng = game_rng.stream(RngStream::Duel);
         for mut duelist in duelist_query.iter_mut() {
-            if !duelist.player {
-                if !duelist.wants_to_attack && !duelist.wants_to_defend {
-                    if player_attack_time < ATTACK_PREP_TIME * 0.5 {
-                        let cooldown_add = if player_cooldown > 0. { 0.05 } else { 0. };
-                        let attack_chance =
-                            (attack_chance + (player_defend_time / 10.0f32) + cooldown_add)
-                                .clamp(0., 1.);
-                        if rng.gen_bool(attack_chance as f64) {
-                            duelist.wants_to_attack = true;
-                        }
-                    }
-                    if !duelist.wants_to_attack && player_attack_time > 0. {
-                        let defend_chance = (0.01f32 + (player_attack_time / 5.0f32)).clamp(0., 1.);
-                        if rng.gen_bool(defend_chance as f64) {
-                            duelist.wants_to_defend = true;
-                        }
+            let controlled = if duelist.player {
+                challenger_ai
+            } else {
+                host_ai
+            };
+            if !controlled {
+                continue;
+            }
+            let opponent = !duelist.player as usize;
+            let opponent_attack_time = attack_times[opponent];
+            let opponent_defend_time = defend_times[opponent];
+            if !duelist.wants_to_attack && !duelist.wants_to_defend {
+                if opponent_attack_time < ATTACK_PREP_TIME * 0.5 {
+                    let cooldown_add = if opponent_defend_time > 0. { 0.05 } else { 0. };
+                    let attack_chance =
+                        (attack_chance + (opponent_defend_time / 10.0f32) + cooldown_add)
+                            .clamp(0., 1.);
+                    if rng.gen_bool(attack_chance as f64) {
+                        duelist.wants_to_attack = true;
                     }
-                } else if duelist.wants_to_attack {
-                    let distance_from_midpoint =
-                        (duelist.attack_time - ATTACK_PREP_TIME * 0.5).abs();
-                    let chance_to_stop_attack = (attack_stop_chance
-                        - attack_stop_chance * distance_from_midpoint.powf(3.))
-                    .clamp(0., 1.);
-                    if rng.gen_bool(chance_to_stop_attack as f64) {
-                        duelist.wants_to_attack = false;
+                }
+                if !duelist.wants_to_attack && opponent_attack_time > 0. {
+                    let defend_chance = (0.01f32 + (opponent_attack_time / 5.0f32)).clamp(0., 1.);
+                    if rng.gen_bool(defend_chance as f64) {
+                        duelist.wants_to_defend = true;
                     }
                 }
+            } else if duelist.wants_to_attack {
+                let distance_from_midpoint = (duelist.attack_time - ATTACK_PREP_TIME * 0.5).abs();
+                let chance_to_stop_attack = (attack_stop_chance
+                    - attack_stop_chance * distance_from_midpoint.powf(3.))
+                .clamp(0., 1.);
+                if rng.gen_bool(chance_to_stop_attack as f64) {
+                    duelist.wants_to_attack = false;
+                }
             }
         }
     }
@@ -1,9 +1,11 @@
 use crate::prelude::*;
+use bevy::ecs::schedule::IntoSystemDescriptor;
 use bevy::prelude::*;
 use bevy_kira_audio::Audio;
 use boats::BoatsPlugin;
 use duel::DuelPlugin;
 use pong::PongPlugin;
+use rand::prelude::*;
 use rps::RpsPlugin;
 
 pub struct MiniGame {
@@ -11,6 +13,8 @@ pub struct MiniGame {
     pub start: bool,
     pub finish: bool,
     pub practice: bool,
+    pub gallery: bool,
+    pub result: Option<Vec<(usize, i32)>>,
     pub display_prefix: String,
     pub display_my_coins: u32,
     pub display_your_coins: u32,
@@ -20,17 +24,148 @@ pub struct MiniGame {
 pub struct MiniGameEntity;
 
 pub struct MiniGameFinish {
-    my_coins: i32,
-    your_coins: i32,
+    results: Vec<(usize, i32)>,
 }
 
+impl MiniGame {
+    pub fn practice_exit(&self) -> GameState {
+        if self.gallery {
+            GameState::Gallery
+        } else {
+            GameState::InfoScreen
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct MiniGameId(pub &'static str);
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub enum MiniGameState {
     Inactive,
-    Rps,
-    Boats,
-    Pong,
-    Duel,
+    Playing(MiniGameId),
+}
+
+pub trait MiniGameInfo: Send + Sync {
+    fn id(&self) -> MiniGameId;
+
+    fn name(&self) -> &'static str;
+
+    fn description(&self, keys: &KeyBindings) -> String;
+
+    fn controls(&self, _keys: &KeyBindings) -> Vec<String> {
+        vec![]
+    }
+
+    fn players(&self) -> usize {
+        2
+    }
+
+    fn rotation(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Default)]
+pub struct MiniGames {
+    games: Vec<Box<dyn MiniGameInfo>>,
+}
+
+impl MiniGames {
+    pub fn register(&mut self, mini_game: impl MiniGameInfo + 'static) {
+        let id = mini_game.id();
+        self.games.retain(|registered| registered.id() != id);
+        self.games.push(Box::new(mini_game));
+    }
+
+    pub fn get(&self, id: MiniGameId) -> Option<&dyn MiniGameInfo> {
+        self.games
+            .iter()
+            .find(|registered| registered.id() == id)
+            .map(|registered| registered.as_ref())
+    }
+
+    pub fn name(&self, id: MiniGameId) -> &'static str {
+        self.get(id).map_or(id.0, |mini_game| mini_game.name())
+    }
+
+    pub fn ids(&self) -> Vec<MiniGameId> {
+        self.games.iter().map(|mini_game| mini_game.id()).collect()
+    }
+
+    pub fn rotation(&self) -> Vec<MiniGameId> {
+        self.games
+            .iter()
+            .filter(|mini_game| mini_game.rotation())
+            .map(|mini_game| mini_game.id())
+            .collect()
+    }
+
+    pub fn pick(&self, recent: &[MiniGameId], rng: &mut impl Rng) -> MiniGameId {
+        let rotation = self.rotation();
+        let weight = |id: &MiniGameId| match recent.iter().rev().position(|played| played == id) {
+            Some(ago) => ago.min(rotation.len()) as u32 + 1,
+            None => rotation.len() as u32 + 1,
+        };
+        rotation
+            .choose_weighted(rng, weight)
+            .ok()
+            .copied()
+            .unwrap_or(duel::ID)
+    }
+
+    pub fn next(
+        &self,
+        game: &mut Game,
+        ruleset: &Ruleset,
+        recent: &[MiniGameId],
+        rng: &mut impl Rng,
+    ) -> MiniGameId {
+        if game.duel {
+            return duel::ID;
+        }
+        if let Some(mini_game) = game.mini_game {
+            return mini_game;
+        }
+        let rigged = story_mini_game(game.turn).filter(|_| ruleset.rigged());
+        let mini_game = rigged.unwrap_or_else(|| self.pick(recent, rng));
+        game.mini_game = Some(mini_game);
+        mini_game
+    }
+}
+
+pub fn story_mini_game(turn: u32) -> Option<MiniGameId> {
+    match turn {
+        1 => Some(boats::ID),
+        2 => Some(rps::ID),
+        3 => Some(pong::ID),
+        _ => None,
+    }
+}
+
+pub trait MiniGameApp {
+    fn add_mini_game<SetupParams, TeardownParams>(
+        &mut self,
+        mini_game: impl MiniGameInfo + 'static,
+        setup: impl IntoSystemDescriptor<SetupParams>,
+        teardown: impl IntoSystemDescriptor<TeardownParams>,
+    ) -> &mut Self;
+}
+
+impl MiniGameApp for App {
+    fn add_mini_game<SetupParams, TeardownParams>(
+        &mut self,
+        mini_game: impl MiniGameInfo + 'static,
+        setup: impl IntoSystemDescriptor<SetupParams>,
+        teardown: impl IntoSystemDescriptor<TeardownParams>,
+    ) -> &mut Self {
+        let state = MiniGameState::Playing(mini_game.id());
+        self.world
+            .get_resource_or_insert_with(MiniGames::default)
+            .register(mini_game);
+        self.add_system_set(SystemSet::on_enter(state).with_system(setup))
+            .add_system_set(SystemSet::on_exit(state).with_system(teardown))
+    }
 }
 
 #[derive(Component)]
@@ -58,15 +193,18 @@ impl Plugin for MiniGamePlugin {
                 start: false,
                 finish: false,
                 practice: false,
+                gallery: false,
+                result: None,
                 display_prefix: "".into(),
                 display_my_coins: 0,
                 display_your_coins: 0,
             })
+            .init_resource::<MiniGames>()
             .add_event::<MiniGameFinish>()
-            .add_plugin(RpsPlugin)
             .add_plugin(BoatsPlugin)
-            .add_plugin(DuelPlugin)
+            .add_plugin(RpsPlugin)
             .add_plugin(PongPlugin)
+            .add_plugin(DuelPlugin)
             .add_system_set(SystemSet::on_enter(GameState::MiniGame).with_system(enter))
             .add_system_set(SystemSet::on_exit(GameState::MiniGame).with_system(exit))
             .add_system_set(SystemSet::on_update(GameState::MiniGame).with_system(update))
@@ -205,7 +343,7 @@ pub fn enter(
     mini_game.start = true;
     mini_game.finish = false;
     mini_game.active = false;
-    if game.turn == 1 && !mini_game.practice {
+    if game.turn == 1 && game.mini_game == Some(boats::ID) && !mini_game.practice {
         dialogue.add(DialogueEntry {
             text: "Huh? How did my boat get smaller?".into(),
             ..Default::default()
@@ -218,7 +356,7 @@ pub fn enter(
             text: "Well, never mind that! Let's start!".into(),
             ..Default::default()
         });
-    } else if game.turn == 2 && !mini_game.practice {
+    } else if game.turn == 2 && game.mini_game == Some(rps::ID) && !mini_game.practice {
         dialogue.add(DialogueEntry {
             text: "Good ol rock paper scissors!".into(),
             ..Default::default()
@@ -227,7 +365,7 @@ pub fn enter(
             text: "Nothing fishy going on here.".into(),
             ..Default::default()
         });
-    } else if game.turn == 3 && !mini_game.practice {
+    } else if game.turn == 3 && game.mini_game == Some(pong::ID) && !mini_game.practice {
         dialogue.add(DialogueEntry {
             text: "Pong? How original.".into(),
             ..Default::default()
@@ -239,32 +377,34 @@ pub fn enter(
     }
 }
 
-pub fn exit(
-    mut mini_game: ResMut<MiniGame>,
-    mut mini_game_state: ResMut<State<MiniGameState>>,
-    destroy_query: Query<Entity, With<MiniGameEntity>>,
-    mut commands: Commands,
-) {
+pub fn exit(mut mini_game: ResMut<MiniGame>, mut mini_game_state: ResMut<State<MiniGameState>>) {
     if !matches!(mini_game_state.current(), MiniGameState::Inactive) {
         mini_game.practice = false;
+        mini_game.gallery = false;
         mini_game_state.set(MiniGameState::Inactive).unwrap();
-        for entity in destroy_query.iter() {
-            commands.entity(entity).despawn_recursive();
-        }
+    }
+}
+
+pub fn teardown(mut commands: Commands, destroy_query: Query<Entity, With<MiniGameEntity>>) {
+    for entity in destroy_query.iter() {
+        commands.entity(entity).despawn_recursive();
     }
 }
 
 pub fn update(
     mut game: ResMut<Game>,
+    mut players: CoinLedger,
     mut mini_game_finish: EventReader<MiniGameFinish>,
     mut game_state: ResMut<State<GameState>>,
     mut mini_game: ResMut<MiniGame>,
     mut text_query: Query<(&mut Text, &mut ReadyText)>,
     mut input: ResMut<Input<KeyCode>>,
-    timer: Res<Time>,
+    timer: Res<Clock>,
     dialogue: Res<Dialogue>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    mini_game_state: Res<State<MiniGameState>>,
+    mut history: ResMut<History>,
 ) {
     if dialogue.busy() {
         for (mut text, _) in text_query.iter_mut() {
@@ -276,24 +416,28 @@ pub fn update(
         let mut done = false;
         for event in mini_game_finish.iter() {
             if !done {
-                if !mini_game.practice {
-                    if event.my_coins < 0 {
-                        if event.my_coins > game.my_coins as i32 {
-                            game.my_coins = 0;
-                        } else {
-                            game.my_coins += event.my_coins as u32;
-                        }
-                    } else {
-                        game.my_coins += event.my_coins as u32;
-                    }
-                    if event.your_coins < 0 {
-                        if event.your_coins > game.your_coins as i32 {
-                            game.your_coins = 0;
-                        } else {
-                            game.your_coins += event.your_coins as u32;
-                        }
+                mini_game.result = Some(event.results.clone());
+                if let (false, MiniGameState::Playing(played)) =
+                    (mini_game.practice, *mini_game_state.current())
+                {
+                    let result = HistoryEvent::MiniGame {
+                        game: played,
+                        results: event.results.clone(),
+                    };
+                    let (event_record, reason) = if played == duel::ID {
+                        (
+                            HistoryEvent::Duel {
+                                challenger: game.challenger,
+                                winner: result.winner(),
+                            },
+                            CoinReason::Duel,
+                        )
                     } else {
-                        game.your_coins += event.your_coins as u32;
+                        (result, CoinReason::MiniGame)
+                    };
+                    history.record(game.turn, event_record);
+                    for (player, delta) in event.results.iter() {
+                        players.change(*player, *delta, reason);
                     }
                 }
                 mini_game.active = false;
@@ -309,7 +453,7 @@ pub fn update(
         mini_game.start = false;
         mini_game.active = false;
         mini_game.finish = false;
-        game_state.set(GameState::InfoScreen).unwrap();
+        game_state.set(mini_game.practice_exit()).unwrap();
         input.reset(KeyCode::Return);
         return;
     }
@@ -347,9 +491,10 @@ pub fn update(
                 mini_game.finish = false;
                 mini_game.active = false;
                 if mini_game.practice {
-                    game_state.set(GameState::InfoScreen).unwrap();
+                    game_state.set(mini_game.practice_exit()).unwrap();
                 } else {
                     game.duel = false;
+                    game.mini_game = None;
                     game_state.set(GameState::Board).unwrap();
                     game.turn += 1;
                 }
@@ -371,6 +516,7 @@ pub fn coin_text(
     mini_game: Res<MiniGame>,
     mut query: Query<(&mut Text, &MiniGameCoinsDisplay)>,
     game: Res<Game>,
+    players: Res<Players>,
 ) {
     for (mut text, display) in query.iter_mut() {
         if mini_game.active || mini_game.finish {
@@ -379,13 +525,13 @@ pub fn coin_text(
                     "Coins: {}{}",
                     mini_game.display_prefix, mini_game.display_my_coins
                 );
-                text.sections[0].style.color = game.my_color;
+                text.sections[0].style.color = players.color(Players::HOST);
             } else {
                 text.sections[0].value = format!(
                     "Coins: {}{}",
                     mini_game.display_prefix, mini_game.display_your_coins
                 );
-                text.sections[0].style.color = game.your_color;
+                text.sections[0].style.color = players.color(game.challenger);
             }
         } else {
             text.sections[0].value = "".into();
@@ -399,5 +545,8 @@ pub mod pong;
 pub mod rps;
 
 pub mod prelude {
-    pub use super::{MiniGame, MiniGameEntity, MiniGameFinish, MiniGameState};
+    pub use super::{
+        MiniGame, MiniGameApp, MiniGameEntity, MiniGameFinish, MiniGameId, MiniGameInfo,
+        MiniGameState, MiniGames,
+    };
 }
@@ -14,6 +14,33 @@ pub struct Pong {
     your_coins: u32,
 }
 
+pub const ID: MiniGameId = MiniGameId("pong");
+
+pub struct PongInfo;
+
+impl MiniGameInfo for PongInfo {
+    fn id(&self) -> MiniGameId {
+        ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Pong"
+    }
+
+    fn description(&self, _keys: &KeyBindings) -> String {
+        "Hit the puck with your paddle!\nIf the puck gets by your opponent, gain 2 coins!\n3 pucks total"
+            .into()
+    }
+
+    fn controls(&self, keys: &KeyBindings) -> Vec<String> {
+        vec![format!(
+            "{} / {} - Move",
+            key_name(keys.left),
+            key_name(keys.right)
+        )]
+    }
+}
+
 pub struct PongPlugin;
 
 impl Plugin for PongPlugin {
@@ -21,29 +48,30 @@ impl Plugin for PongPlugin {
         app.add_plugin(PaddlePlugin)
             .add_plugin(PuckPlugin)
             .insert_resource(Pong::default())
-            .add_system_set(SystemSet::on_enter(MiniGameState::Pong).with_system(init))
-            .add_system_set(SystemSet::on_update(MiniGameState::Pong).with_system(update));
+            .add_mini_game(PongInfo, init, super::teardown)
+            .add_system_set(SystemSet::on_update(MiniGameState::Playing(ID)).with_system(update));
     }
 }
 
 pub fn init(
     game: Res<Game>,
+    players: Res<Players>,
     mut pong: ResMut<Pong>,
     mut commands: Commands,
     mut mini_game: ResMut<MiniGame>,
 ) {
     mini_game.display_prefix = "+".into();
     *pong = Pong::default();
-    commands
-        .spawn_bundle(SpriteBundle {
-            sprite: Sprite {
-                custom_size: Vec2::new(48., 8.).into(),
-                color: game.your_color,
-                ..Default::default()
-            },
-            transform: Transform::from_xyz(0., -80., 0.),
+    let mut your_paddle = commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            custom_size: Vec2::new(48., 8.).into(),
+            color: players.color(game.challenger),
             ..Default::default()
-        })
+        },
+        transform: Transform::from_xyz(0., -80., 0.),
+        ..Default::default()
+    });
+    your_paddle
         .insert(Paddle)
         .insert(Collision {
             shape: CollisionShape::Rect {
@@ -51,18 +79,24 @@ pub fn init(
             },
             flags: 0x1000,
         })
-        .insert(PaddlePlayer)
         .insert(MiniGameEntity);
-    commands
-        .spawn_bundle(SpriteBundle {
-            sprite: Sprite {
-                custom_size: Vec2::new(48., 8.).into(),
-                color: game.my_color,
-                ..Default::default()
-            },
-            transform: Transform::from_xyz(0., 80., 0.),
+    if players.is_human(game.challenger) {
+        your_paddle.insert(PaddlePlayer {
+            player: game.challenger,
+        });
+    } else {
+        your_paddle.insert(PaddleAi::default());
+    }
+    let mut my_paddle = commands.spawn_bundle(SpriteBundle {
+        sprite: Sprite {
+            custom_size: Vec2::new(48., 8.).into(),
+            color: players.color(Players::HOST),
             ..Default::default()
-        })
+        },
+        transform: Transform::from_xyz(0., 80., 0.),
+        ..Default::default()
+    });
+    my_paddle
         .insert(Paddle)
         .insert(Collision {
             shape: CollisionShape::Rect {
@@ -70,14 +104,20 @@ pub fn init(
             },
             flags: 0x1000,
         })
-        .insert(PaddleAi::default())
         .insert(MiniGameEntity);
+    if players.is_human(Players::HOST) {
+        my_paddle.insert(PaddlePlayer {
+            player: Players::HOST,
+        });
+    } else {
+        my_paddle.insert(PaddleAi::default());
+    }
     if !mini_game.practice {
         commands
             .spawn_bundle(SpriteBundle {
                 sprite: Sprite {
                     custom_size: Vec2::new(48., 8.).into(),
-                    color: game.my_color,
+                    color: players.color(Players::HOST),
                     ..Default::default()
                 },
                 transform: Transform::from_xyz(0., 80., 0.),
@@ -96,10 +136,11 @@ pub fn init(
 }
 
 pub fn update(
+    game: Res<Game>,
     mut pong: ResMut<Pong>,
     mut mini_game: ResMut<MiniGame>,
     mut commands: Commands,
-    time: Res<Time>,
+    time: Res<Clock>,
     mut mini_game_finish: EventWriter<MiniGameFinish>,
 ) {
     if mini_game.active && !pong.started {
@@ -139,8 +180,10 @@ pub fn update(
     }
     if pong.my_coins + pong.your_coins == 6 && !pong.finished {
         mini_game_finish.send(MiniGameFinish {
-            my_coins: pong.my_coins as i32,
-            your_coins: pong.your_coins as i32,
+            results: vec![
+                (Players::HOST, pong.my_coins as i32),
+                (game.challenger, pong.your_coins as i32),
+            ],
         });
     }
     mini_game.display_my_coins = pong.my_coins as u32;
@@ -7,7 +7,9 @@ use rand::prelude::*;
 pub struct Paddle;
 
 #[derive(Component)]
-pub struct PaddlePlayer;
+pub struct PaddlePlayer {
+    pub player: usize,
+}
 
 #[derive(Component, Default)]
 pub struct PaddleAi {
@@ -19,8 +21,12 @@ pub struct PaddlePlugin;
 
 impl Plugin for PaddlePlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_update(MiniGameState::Pong).with_system(update));
-        app.add_system_set(SystemSet::on_update(MiniGameState::Pong).with_system(update_ai_target));
+        app.add_system_set(
+            SystemSet::on_update(MiniGameState::Playing(super::ID)).with_system(update),
+        );
+        app.add_system_set(
+            SystemSet::on_update(MiniGameState::Playing(super::ID)).with_system(update_ai_target),
+        );
     }
 }
 
@@ -30,18 +36,20 @@ pub fn update(
         With<Paddle>,
     >,
     input: Res<Input<KeyCode>>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mini_game: Res<MiniGame>,
+    players: Res<Players>,
 ) {
     if !mini_game.active {
         return;
     }
     for (mut transform, player, ai) in paddle_query.iter_mut() {
-        if player.is_some() {
-            if input.pressed(KeyCode::A) {
+        if let Some(player) = player {
+            let keys = players.keys(player.player);
+            if input.pressed(keys.left) {
                 transform.translation.x -= time.delta_seconds() * 250.;
             }
-            if input.pressed(KeyCode::D) {
+            if input.pressed(keys.right) {
                 transform.translation.x += time.delta_seconds() * 250.;
             }
         }
@@ -71,11 +79,12 @@ pub fn update_ai_target(
         QueryState<(&Transform, &mut PaddleAi)>,
     )>,
     mini_game: Res<MiniGame>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     if !mini_game.active {
         return;
     }
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::Pong);
     let mut pucks: Vec<(Entity, Vec2)> = query
         .q0()
         .iter()
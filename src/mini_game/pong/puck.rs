@@ -28,17 +28,18 @@ impl Plugin for PuckPlugin {
 pub fn update(
     mut query: Query<(Entity, &mut Transform, &Collision, &mut Puck)>,
     collision_query: Res<CollisionQuery>,
-    time: Res<Time>,
+    time: Res<Clock>,
     mini_game: ResMut<MiniGame>,
     mut commands: Commands,
     mut pong: ResMut<Pong>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     if !mini_game.active {
         return;
     }
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::Pong);
     for (entity, mut transform, collision, mut puck) in query.iter_mut() {
         let filter = Some(CollisionFilter {
             exclude_entity: entity,
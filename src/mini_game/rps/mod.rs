@@ -21,8 +21,7 @@ pub enum RpsCompare {
 }
 
 impl RpsSelect {
-    pub fn new_rand() -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn new_rand(rng: &mut impl Rng) -> Self {
         match rng.gen_range(0..=2) {
             0 => RpsSelect::Rock,
             1 => RpsSelect::Paper,
@@ -96,19 +95,52 @@ pub struct RpsHand {
 #[derive(Component)]
 pub struct RpsText;
 
+pub const ID: MiniGameId = MiniGameId("rps");
+
+pub struct RpsInfo;
+
+impl MiniGameInfo for RpsInfo {
+    fn id(&self) -> MiniGameId {
+        ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn description(&self, _keys: &KeyBindings) -> String {
+        "Rock, Paper, Scissors, SHOOT!\nReact to your opponent!\nMake your selection quickly AFTER them!\nPlay 3 rounds, round winner gets 2 coins!".into()
+    }
+
+    fn controls(&self, keys: &KeyBindings) -> Vec<String> {
+        vec![
+            format!("{} - Rock", key_name(keys.rock)),
+            format!("{} - Paper", key_name(keys.paper)),
+            format!("{} - Scissors", key_name(keys.scissors)),
+        ]
+    }
+}
+
 pub struct RpsPlugin;
 
 impl Plugin for RpsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(MiniGameState::Rps).with_system(rps_init))
-            .add_system_set(SystemSet::on_update(MiniGameState::Rps).with_system(rps_update))
-            .add_system_set(SystemSet::on_update(MiniGameState::Rps).with_system(rps_update_coins))
-            .add_system_set(SystemSet::on_update(MiniGameState::Rps).with_system(rps_input));
+        app.add_mini_game(RpsInfo, rps_init, super::teardown)
+            .add_system_set(
+                SystemSet::on_update(MiniGameState::Playing(ID)).with_system(rps_update),
+            )
+            .add_system_set(
+                SystemSet::on_update(MiniGameState::Playing(ID)).with_system(rps_update_coins),
+            )
+            .add_system_set(
+                SystemSet::on_update(MiniGameState::Playing(ID)).with_system(rps_input),
+            );
     }
 }
 
 pub fn rps_init(
     game: Res<Game>,
+    players: Res<Players>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
     mut mini_game: ResMut<MiniGame>,
@@ -133,7 +165,7 @@ pub fn rps_init(
             texture_atlas: asset_library.texture_atlas("rps"),
             transform: Transform::from_xyz(-60., 0., 0.3),
             sprite: TextureAtlasSprite {
-                color: game.your_color,
+                color: players.color(game.challenger),
                 ..Default::default()
             },
             ..Default::default()
@@ -145,7 +177,7 @@ pub fn rps_init(
             texture_atlas: asset_library.texture_atlas("rps"),
             transform: Transform::from_xyz(60., 0., 0.3),
             sprite: TextureAtlasSprite {
-                color: game.my_color,
+                color: players.color(Players::HOST),
                 flip_x: true,
                 ..Default::default()
             },
@@ -180,17 +212,21 @@ pub fn rps_update(
     mut hands: Query<(&mut Transform, &mut TextureAtlasSprite, &RpsHand)>,
     mut text_query: Query<&mut Text, With<RpsText>>,
     mut mini_game_finish: EventWriter<MiniGameFinish>,
-    timer: Res<Time>,
+    timer: Res<Clock>,
     mini_game: Res<MiniGame>,
     difficulty: Res<Difficulty>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
     dialogue: Res<Dialogue>,
+    ruleset: Res<Ruleset>,
+    mut game_rng: ResMut<GameRng>,
+    players: Res<Players>,
 ) {
     if dialogue.busy() {
         return;
     }
-    let reshoot_chance = if mini_game.practice {
+    let challenger_human = players.is_human(game.challenger);
+    let reshoot_chance = if mini_game.practice || !ruleset.rigged() {
         0.
     } else if game.turn == 2 {
         1.
@@ -207,7 +243,7 @@ pub fn rps_update(
     if !mini_game.active {
         return;
     }
-    let mut rng = rand::thread_rng();
+    let rng = game_rng.stream(RngStream::Rps);
     for mut controller in query.iter_mut() {
         let RpsController {
             state,
@@ -290,10 +326,16 @@ pub fn rps_update(
                     *can_advance = true;
                 }
                 if *stage == 4 {
+                    let my_selection = RpsSelect::new_rand(rng);
+                    let your_selection = if challenger_human {
+                        RpsSelect::Rock
+                    } else {
+                        RpsSelect::new_rand(rng)
+                    };
                     *state = RpsState::Play {
                         time: 0.0,
-                        my_selection: RpsSelect::new_rand(),
-                        your_selection: RpsSelect::Rock,
+                        my_selection,
+                        your_selection,
                         selection_window: selection_window_time,
                         reshot: false,
                     };
@@ -349,8 +391,10 @@ pub fn rps_update(
                             };
                         } else {
                             mini_game_finish.send(MiniGameFinish {
-                                my_coins: *my_coins as i32,
-                                your_coins: *your_coins as i32,
+                                results: vec![
+                                    (Players::HOST, *my_coins as i32),
+                                    (game.challenger, *your_coins as i32),
+                                ],
                             });
                         }
                     }
@@ -404,115 +448,140 @@ pub fn rps_input(
     difficulty: Res<Difficulty>,
     audio: Res<Audio>,
     asset_library: Res<AssetLibrary>,
+    players: Res<Players>,
 ) {
     // this function has been visited by the game jam fairy
     if dialogue.busy() {
         return;
     }
+    let challenger_human = players.is_human(game.challenger);
+    let keys = players.keys(game.challenger);
     let early_time = match *difficulty {
         Difficulty::Normal => 1.25,
         Difficulty::Hard => 0.75,
     };
     for mut controller in query.iter_mut() {
         let RpsController { state, .. } = controller.as_mut();
-        if let RpsState::Play {
-            your_selection,
-            selection_window,
-            ..
-        } = state
-        {
-            if *selection_window > early_time && game.rps_early_message {
-                if input.just_pressed(KeyCode::R) {
-                    dialogue.add(DialogueEntry {
-                        text: EARLY_MESSAGE.into(),
-                        ..Default::default()
-                    });
-                    *state = RpsState::Countdown {
-                        time: 0.0,
-                        stage: 0,
-                        y: 0.0,
-                        can_advance: true,
-                    };
-                    game.rps_early_message = false;
-                } else if input.just_pressed(KeyCode::P) {
-                    dialogue.add(DialogueEntry {
-                        text: EARLY_MESSAGE.into(),
-                        ..Default::default()
-                    });
-                    *state = RpsState::Countdown {
-                        time: 0.0,
-                        stage: 0,
-                        y: 0.0,
-                        can_advance: true,
-                    };
-                    game.rps_early_message = false;
-                } else if input.just_pressed(KeyCode::S) {
-                    dialogue.add(DialogueEntry {
-                        text: EARLY_MESSAGE.into(),
-                        ..Default::default()
-                    });
-                    *state = RpsState::Countdown {
-                        time: 0.0,
-                        stage: 0,
-                        y: 0.0,
-                        can_advance: true,
-                    };
-                    game.rps_early_message = false;
+        if challenger_human {
+            if let RpsState::Play {
+                your_selection,
+                selection_window,
+                ..
+            } = state
+            {
+                if *selection_window > early_time && game.rps_early_message {
+                    if input.just_pressed(keys.rock) {
+                        dialogue.add(DialogueEntry {
+                            text: EARLY_MESSAGE.into(),
+                            ..Default::default()
+                        });
+                        *state = RpsState::Countdown {
+                            time: 0.0,
+                            stage: 0,
+                            y: 0.0,
+                            can_advance: true,
+                        };
+                        game.rps_early_message = false;
+                    } else if input.just_pressed(keys.paper) {
+                        dialogue.add(DialogueEntry {
+                            text: EARLY_MESSAGE.into(),
+                            ..Default::default()
+                        });
+                        *state = RpsState::Countdown {
+                            time: 0.0,
+                            stage: 0,
+                            y: 0.0,
+                            can_advance: true,
+                        };
+                        game.rps_early_message = false;
+                    } else if input.just_pressed(keys.scissors) {
+                        dialogue.add(DialogueEntry {
+                            text: EARLY_MESSAGE.into(),
+                            ..Default::default()
+                        });
+                        *state = RpsState::Countdown {
+                            time: 0.0,
+                            stage: 0,
+                            y: 0.0,
+                            can_advance: true,
+                        };
+                        game.rps_early_message = false;
+                    }
+                } else if *selection_window > 0. {
+                    if input.just_pressed(keys.rock) {
+                        audio.play(asset_library.audio("shoot"));
+                        *your_selection = RpsSelect::Rock;
+                        *selection_window = 0.;
+                    } else if input.just_pressed(keys.paper) {
+                        audio.play(asset_library.audio("shoot"));
+                        *your_selection = RpsSelect::Paper;
+                        *selection_window = 0.;
+                    } else if input.just_pressed(keys.scissors) {
+                        audio.play(asset_library.audio("shoot"));
+                        *your_selection = RpsSelect::Scissors;
+                        *selection_window = 0.;
+                    }
                 }
-            } else if *selection_window > 0. {
-                if input.just_pressed(KeyCode::R) {
-                    audio.play(asset_library.audio("shoot"));
-                    *your_selection = RpsSelect::Rock;
-                    *selection_window = 0.;
-                } else if input.just_pressed(KeyCode::P) {
-                    audio.play(asset_library.audio("shoot"));
-                    *your_selection = RpsSelect::Paper;
-                    *selection_window = 0.;
-                } else if input.just_pressed(KeyCode::S) {
-                    audio.play(asset_library.audio("shoot"));
-                    *your_selection = RpsSelect::Scissors;
-                    *selection_window = 0.;
+            }
+            if game.rps_early_message {
+                if let RpsState::Countdown { .. } = state {
+                    if input.just_pressed(keys.rock) {
+                        dialogue.add(DialogueEntry {
+                            text: EARLY_MESSAGE.into(),
+                            ..Default::default()
+                        });
+                        *state = RpsState::Countdown {
+                            time: 0.0,
+                            stage: 0,
+                            y: 0.0,
+                            can_advance: true,
+                        };
+                        game.rps_early_message = false;
+                    } else if input.just_pressed(keys.paper) {
+                        dialogue.add(DialogueEntry {
+                            text: EARLY_MESSAGE.into(),
+                            ..Default::default()
+                        });
+                        *state = RpsState::Countdown {
+                            time: 0.0,
+                            stage: 0,
+                            y: 0.0,
+                            can_advance: true,
+                        };
+                        game.rps_early_message = false;
+                    } else if input.just_pressed(keys.scissors) {
+                        dialogue.add(DialogueEntry {
+                            text: EARLY_MESSAGE.into(),
+                            ..Default::default()
+                        });
+                        *state = RpsState::Countdown {
+                            time: 0.0,
+                            stage: 0,
+                            y: 0.0,
+                            can_advance: true,
+                        };
+                        game.rps_early_message = false;
+                    }
                 }
             }
         }
-        if game.rps_early_message {
-            if let RpsState::Countdown { .. } = state {
-                if input.just_pressed(KeyCode::R) {
-                    dialogue.add(DialogueEntry {
-                        text: EARLY_MESSAGE.into(),
-                        ..Default::default()
-                    });
-                    *state = RpsState::Countdown {
-                        time: 0.0,
-                        stage: 0,
-                        y: 0.0,
-                        can_advance: true,
-                    };
-                    game.rps_early_message = false;
-                } else if input.just_pressed(KeyCode::P) {
-                    dialogue.add(DialogueEntry {
-                        text: EARLY_MESSAGE.into(),
-                        ..Default::default()
-                    });
-                    *state = RpsState::Countdown {
-                        time: 0.0,
-                        stage: 0,
-                        y: 0.0,
-                        can_advance: true,
-                    };
-                    game.rps_early_message = false;
-                } else if input.just_pressed(KeyCode::S) {
-                    dialogue.add(DialogueEntry {
-                        text: EARLY_MESSAGE.into(),
-                        ..Default::default()
-                    });
-                    *state = RpsState::Countdown {
-                        time: 0.0,
-                        stage: 0,
-                        y: 0.0,
-                        can_advance: true,
-                    };
-                    game.rps_early_message = false;
+        if players.is_human(Players::HOST) {
+            if let RpsState::Play {
+                my_selection,
+                selection_window,
+                ..
+            } = state
+            {
+                let host_keys = players.keys(Players::HOST);
+                for (key, selection) in [
+                    (host_keys.rock, RpsSelect::Rock),
+                    (host_keys.paper, RpsSelect::Paper),
+                    (host_keys.scissors, RpsSelect::Scissors),
+                ] {
+                    if *selection_window > 0. && input.just_pressed(key) {
+                        audio.play(asset_library.audio("shoot"));
+                        *my_selection = selection;
+                    }
                 }
             }
         }
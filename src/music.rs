@@ -46,7 +46,7 @@ pub fn play_music(
         let desired_music = match *game_state.current() {
             GameState::Intro => Music::Board,
             GameState::Board => Music::Board,
-            GameState::InfoScreen => Music::Info,
+            GameState::InfoScreen | GameState::Gallery => Music::Info,
             GameState::EndGame => Music::EndGame,
             GameState::MiniGame => Music::MiniGame,
             _ => Music::None,
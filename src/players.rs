@@ -0,0 +1,285 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub mod ledger;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 4;
+pub const MAX_ITEMS: usize = 3;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Controller {
+    Host,
+    Human,
+    Cpu,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub rock: KeyCode,
+    pub paper: KeyCode,
+    pub scissors: KeyCode,
+    pub attack: KeyCode,
+    pub defend: KeyCode,
+}
+
+impl KeyBindings {
+    pub const WASD: KeyBindings = KeyBindings {
+        up: KeyCode::W,
+        down: KeyCode::S,
+        left: KeyCode::A,
+        right: KeyCode::D,
+        rock: KeyCode::Key1,
+        paper: KeyCode::Key2,
+        scissors: KeyCode::Key3,
+        attack: KeyCode::Q,
+        defend: KeyCode::E,
+    };
+    pub const ARROWS: KeyBindings = KeyBindings {
+        up: KeyCode::Up,
+        down: KeyCode::Down,
+        left: KeyCode::Left,
+        right: KeyCode::Right,
+        rock: KeyCode::J,
+        paper: KeyCode::K,
+        scissors: KeyCode::L,
+        attack: KeyCode::U,
+        defend: KeyCode::I,
+    };
+    pub const NUMPAD: KeyBindings = KeyBindings {
+        up: KeyCode::Numpad8,
+        down: KeyCode::Numpad5,
+        left: KeyCode::Numpad4,
+        right: KeyCode::Numpad6,
+        rock: KeyCode::Numpad1,
+        paper: KeyCode::Numpad2,
+        scissors: KeyCode::Numpad3,
+        attack: KeyCode::Numpad7,
+        defend: KeyCode::Numpad9,
+    };
+    pub const SETS: [KeyBindings; 3] =
+        [KeyBindings::WASD, KeyBindings::ARROWS, KeyBindings::NUMPAD];
+
+    pub fn movement_label(&self) -> &'static str {
+        if *self == KeyBindings::ARROWS {
+            "ARROWS"
+        } else if *self == KeyBindings::NUMPAD {
+            "NUMPAD 8456"
+        } else {
+            "WASD"
+        }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings::WASD
+    }
+}
+
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{:?}", key).to_uppercase();
+    name.strip_prefix("KEY").map(String::from).unwrap_or(name)
+}
+
+pub struct Player {
+    pub color: Color,
+    coins: u32,
+    pub stars: u32,
+    pub items: Vec<Item>,
+    pub item_use_slot: usize,
+    pub item_use_interpolate: f32,
+    pub pawn: Option<Entity>,
+    pub spawn_tile: Option<usize>,
+    pub next_rolls: Option<Vec<u32>>,
+    pub next_dice: Option<Vec<Die>>,
+    pub controller: Controller,
+    pub keys: KeyBindings,
+}
+
+impl Player {
+    pub fn new(color: Color, controller: Controller) -> Self {
+        Self::with_coins(color, controller, 0)
+    }
+
+    pub fn with_coins(color: Color, controller: Controller, coins: u32) -> Self {
+        Self {
+            color,
+            coins,
+            stars: 0,
+            items: vec![],
+            item_use_slot: 0,
+            item_use_interpolate: 0.,
+            pawn: None,
+            spawn_tile: None,
+            next_rolls: None,
+            next_dice: None,
+            controller,
+            keys: KeyBindings::default(),
+        }
+    }
+
+    pub fn coins(&self) -> u32 {
+        self.coins
+    }
+
+    pub fn has_item(&self, item: Item) -> bool {
+        self.items.contains(&item)
+    }
+
+    pub fn inventory_full(&self) -> bool {
+        self.items.len() >= MAX_ITEMS
+    }
+
+    pub fn give_item(&mut self, item: Item) -> bool {
+        if self.inventory_full() {
+            false
+        } else {
+            self.items.push(item);
+            true
+        }
+    }
+
+    pub fn using_item(&self) -> Item {
+        self.items
+            .get(self.item_use_slot)
+            .copied()
+            .unwrap_or(Item::None)
+    }
+}
+
+#[derive(Default)]
+pub struct Players {
+    pub list: Vec<Player>,
+}
+
+impl Players {
+    pub const HOST: usize = 0;
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn color(&self, index: usize) -> Color {
+        self.list
+            .get(index)
+            .map(|player| player.color)
+            .unwrap_or(Color::WHITE)
+    }
+
+    pub fn coins(&self, index: usize) -> u32 {
+        self.list.get(index).map(|player| player.coins).unwrap_or(0)
+    }
+
+    pub fn is_human(&self, index: usize) -> bool {
+        self.list
+            .get(index)
+            .map(|player| player.controller == Controller::Human)
+            .unwrap_or(false)
+    }
+
+    pub fn keys(&self, index: usize) -> KeyBindings {
+        self.list
+            .get(index)
+            .map(|player| player.keys)
+            .unwrap_or_default()
+    }
+
+    pub fn humans(&self) -> usize {
+        self.list
+            .iter()
+            .filter(|player| player.controller == Controller::Human)
+            .count()
+    }
+
+    pub fn broke(&self, mode: &GameMode) -> bool {
+        !mode.stars() && (self.coins(Players::HOST) == 0 || self.broke_challenger().is_some())
+    }
+
+    pub fn broke_challenger(&self) -> Option<usize> {
+        (1..self.len()).find(|index| self.is_human(*index) && self.coins(*index) == 0)
+    }
+
+    pub fn subject(&self, index: usize) -> String {
+        if index == Players::HOST {
+            "I".into()
+        } else if self.len() == 2 {
+            "You".into()
+        } else {
+            format!("Player {}", index)
+        }
+    }
+
+    pub fn possessive(&self, index: usize) -> String {
+        if index == Players::HOST {
+            "My".into()
+        } else if self.len() == 2 {
+            "Your".into()
+        } else {
+            format!("Player {}'s", index)
+        }
+    }
+
+    pub fn challenger_for_turn(&self, turn: u32) -> usize {
+        let seats = self.len().saturating_sub(1).max(1);
+        1 + (turn.max(1) as usize - 1) % seats
+    }
+
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut ranking: Vec<usize> = (0..self.len()).collect();
+        ranking.sort_by(|a, b| {
+            let (a, b) = (&self.list[*a], &self.list[*b]);
+            (b.stars, b.coins).cmp(&(a.stars, a.coins))
+        });
+        ranking
+    }
+
+    pub fn richest_challenger(&self) -> usize {
+        let mut richest = (1..self.len())
+            .find(|index| self.is_human(*index))
+            .unwrap_or(1);
+        for (index, player) in self.list.iter().enumerate().skip(1) {
+            if player.controller == Controller::Human && player.coins > self.coins(richest) {
+                richest = index;
+            }
+        }
+        richest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_sets_do_not_share_keys() {
+        let keys: Vec<KeyCode> = KeyBindings::SETS
+            .iter()
+            .flat_map(|keys| {
+                [
+                    keys.up,
+                    keys.down,
+                    keys.left,
+                    keys.right,
+                    keys.rock,
+                    keys.paper,
+                    keys.scissors,
+                    keys.attack,
+                    keys.defend,
+                ]
+            })
+            .collect();
+        for (index, key) in keys.iter().enumerate() {
+            assert!(!keys[index + 1..].contains(key), "{:?} is bound twice", key);
+        }
+    }
+}
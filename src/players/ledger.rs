@@ -0,0 +1,196 @@
+use crate::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::ops::Deref;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CoinReason {
+    Start,
+    Tile,
+    Bank,
+    Shop,
+    Star,
+    MiniGame,
+    Duel,
+}
+
+impl CoinReason {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoinReason::Start => "start",
+            CoinReason::Tile => "tile",
+            CoinReason::Bank => "bank",
+            CoinReason::Shop => "shop",
+            CoinReason::Star => "star",
+            CoinReason::MiniGame => "mini game",
+            CoinReason::Duel => "duel",
+        }
+    }
+}
+
+pub struct CoinsChanged {
+    pub player: usize,
+    pub delta: i32,
+    pub reason: CoinReason,
+}
+
+#[derive(SystemParam)]
+pub struct CoinLedger<'w, 's> {
+    players: ResMut<'w, Players>,
+    coins_changed: EventWriter<'w, 's, CoinsChanged>,
+}
+
+impl<'w, 's> CoinLedger<'w, 's> {
+    pub fn change(&mut self, player: usize, delta: i32, reason: CoinReason) -> i32 {
+        let entry = if let Some(entry) = self.players.list.get_mut(player) {
+            entry
+        } else {
+            return 0;
+        };
+        let (coins, applied) = apply_delta(entry.coins, delta);
+        entry.coins = coins;
+        if applied != 0 {
+            self.coins_changed.send(CoinsChanged {
+                player,
+                delta: applied,
+                reason,
+            });
+        }
+        applied
+    }
+
+    pub fn set(&mut self, player: usize, coins: u32, reason: CoinReason) -> i32 {
+        let delta = coins as i32 - self.players.coins(player) as i32;
+        self.change(player, delta, reason)
+    }
+
+    pub fn player_mut(&mut self, player: usize) -> Option<PlayerMut> {
+        self.players
+            .list
+            .get_mut(player)
+            .map(|player| PlayerMut { player })
+    }
+}
+
+pub struct PlayerMut<'a> {
+    player: &'a mut Player,
+}
+
+impl<'a> PlayerMut<'a> {
+    pub fn give_item(&mut self, item: Item) -> bool {
+        let given = self.player.give_item(item);
+        if given {
+            self.player.item_use_interpolate = 0.;
+        }
+        given
+    }
+
+    pub fn remove_item(&mut self, slot: usize) -> Item {
+        self.player.items.remove(slot)
+    }
+
+    pub fn add_star(&mut self) {
+        self.player.stars += 1;
+    }
+}
+
+fn apply_delta(coins: u32, delta: i32) -> (u32, i32) {
+    let before = coins as i32;
+    let after = before.saturating_add(delta).max(0);
+    (after as u32, after - before)
+}
+
+impl<'w, 's> Deref for CoinLedger<'w, 's> {
+    type Target = Players;
+
+    fn deref(&self) -> &Players {
+        &self.players
+    }
+}
+
+pub struct LedgerPlugin;
+
+impl Plugin for LedgerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CoinsChanged>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::SystemState;
+
+    fn world(coins: &[u32]) -> World {
+        let mut world = World::new();
+        let mut players = Players::default();
+        for coins in coins {
+            players
+                .list
+                .push(Player::with_coins(Color::WHITE, Controller::Human, *coins));
+        }
+        world.insert_resource(players);
+        world.insert_resource(Events::<CoinsChanged>::default());
+        world
+    }
+
+    fn changes(world: &World) -> Vec<(usize, i32, CoinReason)> {
+        let events = world.get_resource::<Events<CoinsChanged>>().unwrap();
+        events
+            .get_reader()
+            .iter(events)
+            .map(|event| (event.player, event.delta, event.reason))
+            .collect()
+    }
+
+    #[test]
+    fn apply_delta_floors_at_zero() {
+        assert_eq!(apply_delta(10, 5), (15, 5));
+        assert_eq!(apply_delta(10, -4), (6, -4));
+        assert_eq!(apply_delta(3, -10), (0, -3));
+        assert_eq!(apply_delta(0, -1), (0, 0));
+    }
+
+    #[test]
+    fn change_returns_applied_delta() {
+        let mut world = world(&[5, 20]);
+        let mut state: SystemState<CoinLedger> = SystemState::new(&mut world);
+        let mut ledger = state.get_mut(&mut world);
+        assert_eq!(ledger.change(0, -8, CoinReason::Tile), -5);
+        assert_eq!(ledger.change(0, -8, CoinReason::Tile), 0);
+        assert_eq!(ledger.change(1, 7, CoinReason::MiniGame), 7);
+        assert_eq!(ledger.change(2, 7, CoinReason::MiniGame), 0);
+        assert_eq!(ledger.coins(0), 0);
+        assert_eq!(ledger.coins(1), 27);
+        assert_eq!(
+            changes(&world),
+            vec![(0, -5, CoinReason::Tile), (1, 7, CoinReason::MiniGame)]
+        );
+    }
+
+    #[test]
+    fn set_reports_difference() {
+        let mut world = world(&[12]);
+        let mut state: SystemState<CoinLedger> = SystemState::new(&mut world);
+        let mut ledger = state.get_mut(&mut world);
+        assert_eq!(ledger.set(0, 4, CoinReason::Start), -8);
+        assert_eq!(ledger.set(0, 4, CoinReason::Start), 0);
+        assert_eq!(ledger.set(0, 30, CoinReason::Start), 26);
+        assert_eq!(ledger.coins(0), 30);
+        assert_eq!(
+            changes(&world),
+            vec![(0, -8, CoinReason::Start), (0, 26, CoinReason::Start)]
+        );
+    }
+
+    #[test]
+    fn player_mut_checks_index() {
+        let mut world = world(&[3]);
+        let mut state: SystemState<CoinLedger> = SystemState::new(&mut world);
+        let mut ledger = state.get_mut(&mut world);
+        assert!(ledger.player_mut(1).is_none());
+        ledger.player_mut(0).unwrap().add_star();
+        assert_eq!(ledger.list[0].stars, 1);
+        assert!(changes(&world).is_empty());
+    }
+}
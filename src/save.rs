@@ -0,0 +1,372 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+
+pub const SAVE_VERSION: u32 = 5;
+pub const SAVE_PATH: &str = "save.ron";
+const SAVE_LAYOUT: &str = "default";
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedPlayer {
+    pub color: [f32; 4],
+    pub coins: u32,
+    pub items: Vec<Item>,
+    pub controller: Controller,
+    pub keys: KeyBindings,
+    pub tile: usize,
+    pub next_rolls: Option<Vec<u32>>,
+    pub next_dice: Option<Vec<Die>>,
+    pub stars: u32,
+}
+
+#[derive(Deserialize)]
+struct SaveVersion {
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SaveData {
+    pub version: u32,
+    pub layout: String,
+    pub layout_fingerprint: u64,
+    pub difficulty: Difficulty,
+    pub ruleset: Ruleset,
+    pub mode: GameMode,
+    pub turn: u32,
+    pub challenger: usize,
+    pub duel: bool,
+    pub practice_first_message: bool,
+    pub rps_early_message: bool,
+    pub bank: u32,
+    pub first_shop: bool,
+    pub shop_sold: Vec<(Item, u32)>,
+    pub rapier_dialog: bool,
+    pub star_tile: Option<usize>,
+    pub players: Vec<SavedPlayer>,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Parse(ron::Error),
+    Version { found: u32, expected: u32 },
+    UnknownLayout(String),
+    LayoutChanged(String),
+    BadTile { player: usize, tile: usize },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "could not access the save file: {}", err),
+            SaveError::Parse(err) => write!(f, "the save file is corrupted: {}", err),
+            SaveError::Version { found, expected } => write!(
+                f,
+                "the save file is version {}, but this game needs version {}",
+                found, expected
+            ),
+            SaveError::UnknownLayout(layout) => {
+                write!(f, "the save file uses an unknown board \"{}\"", layout)
+            }
+            SaveError::LayoutChanged(layout) => {
+                write!(
+                    f,
+                    "the board \"{}\" changed since the game was saved",
+                    layout
+                )
+            }
+            SaveError::BadTile { player, tile } => write!(
+                f,
+                "player {} is saved on tile {}, which does not exist",
+                player, tile
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<std::io::Error> for SaveError {
+    fn from(err: std::io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<ron::Error> for SaveError {
+    fn from(err: ron::Error) -> Self {
+        SaveError::Parse(err)
+    }
+}
+
+pub fn save_exists() -> bool {
+    std::path::Path::new(SAVE_PATH).exists()
+}
+
+pub fn delete_save() {
+    if save_exists() {
+        if let Err(err) = fs::remove_file(SAVE_PATH) {
+            error!("failed to delete save: {}", err);
+        }
+    }
+}
+
+pub fn load_save(layout: Option<&BoardLayout>) -> Result<SaveData, SaveError> {
+    let text = fs::read_to_string(SAVE_PATH)?;
+    parse_save(&text, layout)
+}
+
+fn parse_save(text: &str, layout: Option<&BoardLayout>) -> Result<SaveData, SaveError> {
+    let version: SaveVersion = ron::from_str(text)?;
+    if version.version != SAVE_VERSION {
+        return Err(SaveError::Version {
+            found: version.version,
+            expected: SAVE_VERSION,
+        });
+    }
+    let save: SaveData = ron::from_str(text)?;
+    let layout = match layout {
+        Some(layout) if save.layout == SAVE_LAYOUT => layout,
+        _ => return Err(SaveError::UnknownLayout(save.layout)),
+    };
+    if save.layout_fingerprint != layout.fingerprint() {
+        return Err(SaveError::LayoutChanged(save.layout));
+    }
+    for (player, saved) in save.players.iter().enumerate() {
+        if saved.tile >= layout.tiles.len() {
+            return Err(SaveError::BadTile {
+                player,
+                tile: saved.tile,
+            });
+        }
+    }
+    Ok(save)
+}
+
+impl SaveData {
+    pub fn apply(
+        self,
+        game: &mut Game,
+        board: &mut Board,
+        players: &mut Players,
+        difficulty: &mut Difficulty,
+        ruleset: &mut Ruleset,
+        mode: &mut GameMode,
+        history: &mut History,
+    ) {
+        // History is not saved, so stats and mini game recency start over from the loaded turn.
+        *history = History::default();
+        info!("continuing from turn {} without earlier history", self.turn);
+        *difficulty = self.difficulty;
+        *ruleset = self.ruleset;
+        *mode = self.mode;
+        game.turn = self.turn;
+        game.challenger = self.challenger;
+        game.duel = self.duel;
+        game.practice_first_message = self.practice_first_message;
+        game.rps_early_message = self.rps_early_message;
+        board.bank = self.bank;
+        board.first_shop = self.first_shop;
+        board.shop_sold = self.shop_sold.into_iter().collect();
+        board.rapier_dialog = self.rapier_dialog;
+        board.star_tile = self
+            .star_tile
+            .and_then(|tile| board.tiles.get(tile).copied());
+        players.list = self
+            .players
+            .into_iter()
+            .map(|saved| {
+                let [r, g, b, a] = saved.color;
+                let mut player =
+                    Player::with_coins(Color::rgba(r, g, b, a), saved.controller, saved.coins);
+                player.items = saved.items;
+                player.keys = saved.keys;
+                player.spawn_tile = Some(saved.tile);
+                player.next_rolls = saved.next_rolls;
+                player.next_dice = saved.next_dice;
+                player.stars = saved.stars;
+                player
+            })
+            .collect();
+    }
+}
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(BoardState::TurnIntro).with_system(save));
+    }
+}
+
+pub fn save(
+    game: Res<Game>,
+    board: Res<Board>,
+    players: Res<Players>,
+    difficulty: Res<Difficulty>,
+    ruleset: Res<Ruleset>,
+    mode: Res<GameMode>,
+    replay: Res<Replay>,
+    asset_library: Res<AssetLibrary>,
+    board_layouts: Res<Assets<BoardLayout>>,
+    pawn_query: Query<&Pawn>,
+) {
+    if board.current != Players::HOST || replay.is_playing() {
+        return;
+    }
+    let layout = if let Some(layout) = board_layouts.get(asset_library.board(SAVE_LAYOUT)) {
+        layout
+    } else {
+        return;
+    };
+    let saved_players = players
+        .list
+        .iter()
+        .map(|player| {
+            let tile = player
+                .pawn
+                .and_then(|pawn| pawn_query.get(pawn).ok())
+                .and_then(|pawn| board.tiles.iter().position(|tile| *tile == pawn.tile))
+                .unwrap_or(layout.start);
+            SavedPlayer {
+                color: player.color.as_rgba_f32(),
+                coins: player.coins(),
+                items: player.items.clone(),
+                controller: player.controller,
+                keys: player.keys,
+                tile,
+                next_rolls: player.next_rolls.clone(),
+                next_dice: player.next_dice.clone(),
+                stars: player.stars,
+            }
+        })
+        .collect();
+    let save = SaveData {
+        version: SAVE_VERSION,
+        layout: SAVE_LAYOUT.into(),
+        layout_fingerprint: layout.fingerprint(),
+        difficulty: *difficulty,
+        ruleset: *ruleset,
+        mode: *mode,
+        turn: game.turn,
+        challenger: game.challenger,
+        duel: game.duel,
+        practice_first_message: game.practice_first_message,
+        rps_early_message: game.rps_early_message,
+        bank: board.bank,
+        first_shop: board.first_shop,
+        shop_sold: board
+            .shop_sold
+            .iter()
+            .map(|(item, sold)| (*item, *sold))
+            .collect(),
+        rapier_dialog: board.rapier_dialog,
+        star_tile: board
+            .star_tile
+            .and_then(|star_tile| board.tiles.iter().position(|tile| *tile == star_tile)),
+        players: saved_players,
+    };
+    let result = ron::ser::to_string_pretty(&save, ron::ser::PrettyConfig::default())
+        .map_err(SaveError::from)
+        .and_then(|text| Ok(fs::write(SAVE_PATH, text)?));
+    if let Err(err) = result {
+        error!("failed to save game: {}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> BoardLayout {
+        ron::de::from_str(include_str!("../assets/boards/default.board.ron")).unwrap()
+    }
+
+    fn save_data(layout: &BoardLayout) -> SaveData {
+        SaveData {
+            version: SAVE_VERSION,
+            layout: SAVE_LAYOUT.into(),
+            layout_fingerprint: layout.fingerprint(),
+            difficulty: Difficulty::Normal,
+            ruleset: Ruleset::FairPlay,
+            mode: GameMode::Elimination,
+            turn: 4,
+            challenger: 1,
+            duel: false,
+            practice_first_message: false,
+            rps_early_message: false,
+            bank: 0,
+            first_shop: true,
+            shop_sold: vec![],
+            rapier_dialog: false,
+            star_tile: None,
+            players: vec![SavedPlayer {
+                color: [1., 1., 1., 1.],
+                coins: 10,
+                items: vec![],
+                controller: Controller::Human,
+                keys: KeyBindings::WASD,
+                tile: layout.start,
+                next_rolls: None,
+                next_dice: None,
+                stars: 0,
+            }],
+        }
+    }
+
+    fn parse(save: &SaveData, layout: &BoardLayout) -> Result<SaveData, SaveError> {
+        parse_save(&ron::to_string(save).unwrap(), Some(layout))
+    }
+
+    #[test]
+    fn valid_save() {
+        let layout = layout();
+        let save = parse(&save_data(&layout), &layout).unwrap();
+        assert_eq!(save.turn, 4);
+        assert_eq!(save.players[0].coins, 10);
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let layout = layout();
+        let mut save = save_data(&layout);
+        save.version = SAVE_VERSION - 1;
+        assert!(matches!(
+            parse(&save, &layout),
+            Err(SaveError::Version { found, expected })
+                if found == SAVE_VERSION - 1 && expected == SAVE_VERSION
+        ));
+    }
+
+    #[test]
+    fn old_version_is_reported_before_parsing() {
+        let text = format!("(version: {}, bank: \"gone\")", SAVE_VERSION - 1);
+        assert!(matches!(
+            parse_save(&text, Some(&layout())),
+            Err(SaveError::Version { .. })
+        ));
+    }
+
+    #[test]
+    fn fingerprint_mismatch() {
+        let layout = layout();
+        let mut save = save_data(&layout);
+        save.layout_fingerprint = layout.fingerprint().wrapping_add(1);
+        assert!(matches!(
+            parse(&save, &layout),
+            Err(SaveError::LayoutChanged(_))
+        ));
+    }
+
+    #[test]
+    fn bad_tile() {
+        let layout = layout();
+        let mut save = save_data(&layout);
+        save.players[0].tile = layout.tiles.len();
+        assert!(matches!(
+            parse(&save, &layout),
+            Err(SaveError::BadTile { player: 0, tile }) if tile == layout.tiles.len()
+        ));
+    }
+}
@@ -99,32 +99,109 @@ pub fn exit(mut commands: Commands, query: Query<Entity, With<SetupEntity>>) {
     }
 }
 
+const SEAT_COLORS: [Color; 4] = [Color::CYAN, Color::YELLOW, Color::ORANGE, Color::LIME_GREEN];
+const MODES: [GameMode; 4] = [
+    GameMode::Elimination,
+    GameMode::Stars { turn_limit: 10 },
+    GameMode::Stars { turn_limit: 15 },
+    GameMode::Stars { turn_limit: 20 },
+];
+
+#[derive(Default)]
+pub struct SetupOptions {
+    player_count: usize,
+    humans: [bool; MAX_PLAYERS],
+    fair_play: bool,
+    mode: usize,
+}
+
 pub fn update(
-    mut game: ResMut<Game>,
+    mut players: ResMut<Players>,
     input: Res<Input<KeyCode>>,
     mut game_state: ResMut<State<GameState>>,
     mut text_query: Query<&mut Text, With<SetupText>>,
     mut difficulty: ResMut<Difficulty>,
+    mut ruleset: ResMut<Ruleset>,
+    mut mode: ResMut<GameMode>,
+    mut options: Local<SetupOptions>,
 ) {
+    options.player_count = options.player_count.clamp(MIN_PLAYERS, MAX_PLAYERS);
+    if input.just_pressed(KeyCode::Left) {
+        options.player_count = (options.player_count - 1).max(MIN_PLAYERS);
+    } else if input.just_pressed(KeyCode::Right) {
+        options.player_count = (options.player_count + 1).min(MAX_PLAYERS);
+    }
+    for (seat, key) in [(2, KeyCode::Key2), (3, KeyCode::Key3)] {
+        if input.just_pressed(key) && seat < options.player_count {
+            options.humans[seat] = !options.humans[seat];
+        }
+    }
+    if input.just_pressed(KeyCode::F) {
+        options.fair_play = !options.fair_play;
+    }
+    if input.just_pressed(KeyCode::M) {
+        options.mode = (options.mode + 1) % MODES.len();
+    }
+    let mut choice = None;
     if input.just_pressed(KeyCode::Y) {
-        game.your_color = Color::YELLOW;
-        game_state.set(GameState::Intro).unwrap();
-        *difficulty = Difficulty::Normal;
+        choice = Some((Color::YELLOW, Difficulty::Normal));
     } else if input.just_pressed(KeyCode::B) {
-        game.your_color = Color::CYAN;
-        game_state.set(GameState::Intro).unwrap();
-        *difficulty = Difficulty::Normal;
+        choice = Some((Color::CYAN, Difficulty::Normal));
     } else if input.just_pressed(KeyCode::O) {
-        game.your_color = Color::ORANGE;
-        *difficulty = Difficulty::Normal;
-        game_state.set(GameState::Intro).unwrap();
+        choice = Some((Color::ORANGE, Difficulty::Normal));
     } else if input.just_pressed(KeyCode::Key1) {
-        game.your_color = Color::ORANGE_RED;
+        choice = Some((Color::ORANGE_RED, Difficulty::Hard));
+    }
+    if let Some((color, choice_difficulty)) = choice {
+        let mut you = Player::new(color, Controller::Human);
+        you.keys = KeyBindings::SETS[0];
+        players.list = vec![
+            Player::new(Color::rgba(1., 0.7, 0.7, 1.0), Controller::Host),
+            you,
+        ];
+        players.list[Players::HOST].items = vec![Item::TrumpCard];
+        let mut seat_colors = SEAT_COLORS
+            .iter()
+            .filter(|seat_color| **seat_color != color);
+        for seat in 2..options.player_count {
+            let seat_color = seat_colors.next().copied().unwrap_or(Color::WHITE);
+            if options.humans[seat] {
+                let mut player = Player::new(seat_color, Controller::Human);
+                player.keys = KeyBindings::SETS[players.humans() % KeyBindings::SETS.len()];
+                players.list.push(player);
+            } else {
+                players.list.push(Player::new(seat_color, Controller::Cpu));
+            }
+        }
+        *difficulty = choice_difficulty;
+        *ruleset = if options.fair_play {
+            Ruleset::FairPlay
+        } else {
+            Ruleset::Story
+        };
+        *mode = MODES[options.mode];
         game_state.set(GameState::Intro).unwrap();
-        *difficulty = Difficulty::Hard;
     }
+    let mut seats = String::new();
+    for seat in 2..options.player_count {
+        seats.push_str(&format!(
+            "\n{} - Player {}: {}",
+            seat,
+            seat,
+            if options.humans[seat] { "Human" } else { "CPU" }
+        ));
+    }
+    let mode_name = match MODES[options.mode] {
+        GameMode::Elimination => "Elimination".to_string(),
+        GameMode::Stars { turn_limit } => format!("Stars, {} Turns", turn_limit),
+    };
     for mut text in text_query.iter_mut() {
-        text.sections[0].value =
-            "Select Your Color:\n\n\n\n\nB - Blue\nY - Yellow\nO - Orange".into();
+        text.sections[0].value = format!(
+            "Select Your Color:\n\n\n\n\nB - Blue\nY - Yellow\nO - Orange\n\nLEFT/RIGHT - Players: {}{}\nF - Fair Play: {}\nM - Mode: {}",
+            options.player_count,
+            seats,
+            if options.fair_play { "On" } else { "Off" },
+            mode_name
+        );
     }
 }
@@ -0,0 +1,374 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+const CHART_LEFT: f32 = 40.;
+const CHART_BOTTOM: f32 = 290.;
+const CHART_WIDTH: f32 = 560.;
+const CHART_HEIGHT: f32 = 120.;
+const CHART_DOT: f32 = 3.;
+const CHART_COLOR: Color = Color::rgba(1., 1., 1., 0.15);
+
+#[derive(Component)]
+pub struct StatsEntity;
+
+#[derive(Default)]
+pub struct PlayerStats {
+    pub coins: Vec<u32>,
+    pub mini_game_wins: u32,
+    pub duel_wins: u32,
+    pub duel_losses: u32,
+    pub tiles: HashMap<TileType, u32>,
+    pub rolls: Vec<u32>,
+    pub items_bought: u32,
+    pub items_used: u32,
+    pub coins_spent: u32,
+}
+
+impl PlayerStats {
+    pub fn average_roll(&self) -> Option<f32> {
+        if self.rolls.is_empty() {
+            None
+        } else {
+            Some(self.rolls.iter().sum::<u32>() as f32 / self.rolls.len() as f32)
+        }
+    }
+}
+
+pub struct Award {
+    pub title: &'static str,
+    pub player: usize,
+    pub detail: String,
+}
+
+pub fn player_stats(history: &History, players: &Players, turn: u32) -> Vec<PlayerStats> {
+    let mut stats: Vec<PlayerStats> = (0..players.len()).map(|_| PlayerStats::default()).collect();
+    let mut deltas = vec![vec![0; turn as usize + 1]; players.len()];
+    for entry in history.entries() {
+        match entry.event {
+            HistoryEvent::Coins {
+                player,
+                delta,
+                reason,
+            } => {
+                let turn = if reason == CoinReason::Start {
+                    0
+                } else {
+                    entry.turn.min(turn)
+                };
+                deltas[player][turn as usize] += delta;
+                if reason == CoinReason::Shop && delta < 0 {
+                    stats[player].coins_spent += delta.unsigned_abs();
+                }
+            }
+            HistoryEvent::Rolled { player, total } => stats[player].rolls.push(total),
+            HistoryEvent::Landed { player, tile } => {
+                *stats[player].tiles.entry(tile).or_insert(0) += 1;
+            }
+            HistoryEvent::Bought { player, .. } => stats[player].items_bought += 1,
+            HistoryEvent::UsedItem { player, .. } => stats[player].items_used += 1,
+            HistoryEvent::MiniGame { .. } => {
+                if let Some(winner) = entry.event.winner() {
+                    stats[winner].mini_game_wins += 1;
+                }
+            }
+            HistoryEvent::Duel { challenger, winner } => {
+                if let Some(winner) = winner {
+                    let loser = if winner == Players::HOST {
+                        challenger
+                    } else {
+                        Players::HOST
+                    };
+                    stats[winner].mini_game_wins += 1;
+                    stats[winner].duel_wins += 1;
+                    stats[loser].duel_losses += 1;
+                }
+            }
+            HistoryEvent::Discarded { .. } | HistoryEvent::StarBought { .. } => {}
+        }
+    }
+    for (player, stats) in stats.iter_mut().enumerate() {
+        let mut coins = players.coins(player) as i32;
+        let mut balances = vec![coins.max(0) as u32];
+        for delta in deltas[player].iter().skip(1).rev() {
+            coins -= delta;
+            balances.push(coins.max(0) as u32);
+        }
+        balances.reverse();
+        stats.coins = balances;
+    }
+    stats
+}
+
+pub fn awards(stats: &[PlayerStats]) -> Vec<Award> {
+    let mut awards = vec![];
+    if let Some((player, stats)) = stats
+        .iter()
+        .enumerate()
+        .filter(|(_, stats)| stats.mini_game_wins > 0)
+        .max_by_key(|(_, stats)| stats.mini_game_wins)
+    {
+        awards.push(Award {
+            title: "Mini Game Star",
+            player,
+            detail: format!("{} wins", stats.mini_game_wins),
+        });
+    }
+    if let Some((player, average)) = stats
+        .iter()
+        .enumerate()
+        .filter_map(|(player, stats)| Some((player, stats.average_roll()?)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+    {
+        awards.push(Award {
+            title: "Unluckiest Roller",
+            player,
+            detail: format!("averaged {:.1}", average),
+        });
+    }
+    if let Some((player, stats)) = stats
+        .iter()
+        .enumerate()
+        .filter(|(_, stats)| stats.coins_spent > 0)
+        .max_by_key(|(_, stats)| stats.coins_spent)
+    {
+        awards.push(Award {
+            title: "Big Spender",
+            player,
+            detail: format!("{} coins at the shop", stats.coins_spent),
+        });
+    }
+    if let Some((player, reds)) = stats
+        .iter()
+        .enumerate()
+        .map(|(player, stats)| {
+            (
+                player,
+                stats.tiles.get(&TileType::Red).copied().unwrap_or(0),
+            )
+        })
+        .filter(|(_, reds)| *reds > 0)
+        .max_by_key(|(_, reds)| *reds)
+    {
+        awards.push(Award {
+            title: "Red Magnet",
+            player,
+            detail: format!("{} red tiles", reds),
+        });
+    }
+    awards
+}
+
+fn summary_text(stats: &[PlayerStats], players: &Players) -> String {
+    let tile_types = [
+        TileType::Blue,
+        TileType::Red,
+        TileType::Green,
+        TileType::Event,
+        TileType::Warp,
+        TileType::Bank,
+        TileType::Duel,
+    ];
+    let mut lines = vec![];
+    for (player, stats) in stats.iter().enumerate() {
+        lines.push(format!(
+            "{}: {} mini games, duels {}-{}, items {} bought / {} used",
+            players.subject(player),
+            stats.mini_game_wins,
+            stats.duel_wins,
+            stats.duel_losses,
+            stats.items_bought,
+            stats.items_used
+        ));
+        let tiles: Vec<String> = tile_types
+            .iter()
+            .filter_map(|tile_type| {
+                let count = stats.tiles.get(tile_type).copied().unwrap_or(0);
+                (count > 0).then(|| format!("{} {}", tile_type.name(), count))
+            })
+            .collect();
+        if !tiles.is_empty() {
+            lines.push(format!("    {}", tiles.join(", ")));
+        }
+    }
+    lines.join("\n")
+}
+
+fn awards_text(awards: &[Award], players: &Players) -> String {
+    let mut lines: Vec<String> = awards
+        .iter()
+        .map(|award| {
+            format!(
+                "{}: {} ({})",
+                award.title,
+                players.subject(award.player),
+                award.detail
+            )
+        })
+        .collect();
+    lines.push("\nENTER - Continue".into());
+    lines.join("\n")
+}
+
+fn text_bundle(text: String, top: f32, font_size: f32, asset_library: &AssetLibrary) -> TextBundle {
+    TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: Rect {
+                left: Val::Px(CHART_LEFT),
+                top: Val::Px(top),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        text: Text::with_section(
+            text,
+            TextStyle {
+                font: asset_library.font("game"),
+                font_size,
+                color: Color::WHITE,
+            },
+            TextAlignment {
+                horizontal: HorizontalAlign::Left,
+                vertical: VerticalAlign::Top,
+            },
+        ),
+        ..Default::default()
+    }
+}
+
+fn chart_dot(position: Vec2, color: Color) -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: Rect {
+                left: Val::Px(position.x - CHART_DOT / 2.),
+                bottom: Val::Px(position.y - CHART_DOT / 2.),
+                ..Default::default()
+            },
+            size: Size::new(Val::Px(CHART_DOT), Val::Px(CHART_DOT)),
+            ..Default::default()
+        },
+        color: color.into(),
+        ..Default::default()
+    }
+}
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(GameState::Stats).with_system(enter))
+            .add_system_set(SystemSet::on_exit(GameState::Stats).with_system(exit))
+            .add_system_set(SystemSet::on_update(GameState::Stats).with_system(update));
+    }
+}
+
+pub fn enter(
+    mut commands: Commands,
+    mut camera_controller: ResMut<CameraController>,
+    asset_library: Res<AssetLibrary>,
+    history: Res<History>,
+    players: Res<Players>,
+    game: Res<Game>,
+) {
+    camera_controller.center = true;
+    let stats = player_stats(&history, &players, game.turn);
+    let awards = awards(&stats);
+    let max_coins = stats
+        .iter()
+        .flat_map(|stats| stats.coins.iter().copied())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let points = stats
+        .iter()
+        .map(|stats| stats.coins.len())
+        .max()
+        .unwrap_or(0);
+    let chart_point = |index: usize, coins: u32| {
+        let x = if points > 1 {
+            index as f32 / (points - 1) as f32 * CHART_WIDTH
+        } else {
+            0.
+        };
+        Vec2::new(
+            CHART_LEFT + x,
+            CHART_BOTTOM + coins as f32 / max_coins as f32 * CHART_HEIGHT,
+        )
+    };
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            color: Color::BLACK.into(),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn_bundle(text_bundle(
+                format!("Results - Turn {}", game.turn),
+                12.,
+                28.,
+                &asset_library,
+            ));
+            parent.spawn_bundle(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: Rect {
+                        left: Val::Px(CHART_LEFT),
+                        bottom: Val::Px(CHART_BOTTOM),
+                        ..Default::default()
+                    },
+                    size: Size::new(Val::Px(CHART_WIDTH), Val::Px(CHART_HEIGHT)),
+                    ..Default::default()
+                },
+                color: CHART_COLOR.into(),
+                ..Default::default()
+            });
+            for (player, stats) in stats.iter().enumerate() {
+                let color = players.color(player);
+                for (index, pair) in stats.coins.windows(2).enumerate() {
+                    let from = chart_point(index, pair[0]);
+                    let to = chart_point(index + 1, pair[1]);
+                    let steps = ((to - from).abs().max_element() / CHART_DOT).ceil().max(1.);
+                    for step in 0..steps as u32 {
+                        let position = from.lerp(to, step as f32 / steps);
+                        parent.spawn_bundle(chart_dot(position, color));
+                    }
+                }
+                if let Some(coins) = stats.coins.last() {
+                    parent
+                        .spawn_bundle(chart_dot(chart_point(stats.coins.len() - 1, *coins), color));
+                }
+            }
+            parent.spawn_bundle(text_bundle(
+                summary_text(&stats, &players),
+                200.,
+                14.,
+                &asset_library,
+            ));
+            parent.spawn_bundle(text_bundle(
+                awards_text(&awards, &players),
+                345.,
+                16.,
+                &asset_library,
+            ));
+        })
+        .insert(StatsEntity);
+}
+
+pub fn exit(mut commands: Commands, query: Query<Entity, With<StatsEntity>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update(input: Res<Input<KeyCode>>, mut reset: EventWriter<GameResetSend>) {
+    if input.just_pressed(KeyCode::Return) {
+        reset.send(GameResetSend);
+    }
+}